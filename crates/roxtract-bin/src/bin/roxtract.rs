@@ -1,4 +1,4 @@
-use std::{ffi::{OsString, OsStr}, fs, io::{Read, self}, error::Error, fmt};
+use std::{ffi::{OsString, OsStr}, fs, io::{Read, self}, error::Error, fmt, path::PathBuf};
 
 use roxtract::*;
 
@@ -11,6 +11,18 @@ struct CliArgs {
 
 	#[options(help = "show help on usage")]
 	help: bool,
+
+	#[options(no_short, meta = "DIR", help = "write each module to DIR/<index>-<name>.module")]
+	extract_all: Option<PathBuf>,
+
+	#[options(no_short, help = "list complete module titles, including any text after a tab")]
+	full_titles: bool,
+
+	#[options(help = "only identify the ROM; don't list its modules")]
+	quiet: bool,
+
+	#[options(no_short, help = "print only a CSV table of the modules in the ROM")]
+	csv: bool,
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -44,22 +56,63 @@ fn main() -> Result<(), Box<dyn Error>> {
 	}
 
 	let args: CliArgs = gumdrop::parse_args_default_or_exit::<CliArgs>();
+	if args.csv && (args.extract_all.is_some() || args.quiet || args.full_titles) {
+		return Err("--csv cannot be combined with --extract-all, --quiet or --full-titles".into());
+	}
 
 	let rom = Rom::from_file(args.rom_path)?;
+	if args.csv {
+		print!("{}", rom.module_table_csv());
+		return Ok(());
+	}
+
+	match rom.identify() {
+		Identification::Identified(v) => println!("Version: {}", v.name_high_level),
+		Identification::PatchedVariant(v) => println!("Version: {} (patched)", v.name_high_level),
+		Identification::Unknown => println!("Version: [unknown]"),
+	}
+	println!("CRC32: {:08x}", rom.crc32());
 	println!("Kernel starts at {:04x}", rom.kernel_start().or_print("[not found]"));
 	println!("Module chain starts at {:04x}", rom.module_chain_start().or_print("[UtilityModule not found]"));
 
-	for module in rom.module_chain() {
-		print!("module: ");
-		for ch in module.title()?.as_ref() {
-			print!("{}", (*ch as char).escape_default())
+	if !args.quiet {
+		for module in rom.module_chain() {
+			print!("module: ");
+			let title = module.title()?;
+			let title = if args.full_titles { title } else { title.split(b'\t').next().unwrap() };
+			for ch in title.as_ref() {
+				print!("{}", (*ch as char).escape_default())
+			}
+			println!(" (size {} bytes) at {:06x}", module.data().len(), module.offset());
+		}
+	}
+
+	if let Some(dir) = args.extract_all {
+		fs::create_dir_all(&dir)?;
+		let mut written = 0;
+		for (index, module) in rom.module_chain().enumerate() {
+			// the index prefix keeps names unique, even where sanitising makes titles collide
+			let name = module.title().map_or_else(|_| "unnamed".to_owned(), file_name_for);
+			module.extract_to_file(dir.join(format!("{:03}-{}.module", index, name)))?;
+			written += 1;
 		}
-		println!(" (size {} bytes) at {:06x}", module.data().len(), module.offset());
+		println!("Wrote {} modules to {}", written, dir.display());
 	}
 
 	Ok(())
 }
 
+/// Converts a module title into something safe to use in a file name on any platform, by
+/// replacing every character other than ASCII letters, digits, `-` and `_` with `_`.
+fn file_name_for(title: &Slice32) -> String {
+	match title.as_ref() {
+		[] => "unnamed".to_owned(),
+		bytes => bytes.iter()
+			.map(|&b| if b.is_ascii_alphanumeric() || b == b'-' { b as char } else { '_' })
+			.collect(),
+	}
+}
+
 struct HexOr<T>(Option<T>, &'static str);
 
 impl<T: fmt::LowerHex + Copy> fmt::LowerHex for HexOr<T> {
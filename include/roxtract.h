@@ -0,0 +1,86 @@
+#ifndef ROXTRACT_H
+#define ROXTRACT_H
+
+/* Generated with cbindgen; do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The call succeeded.
+ */
+#define ROXTRACT_OK 0
+
+/**
+ * A required pointer argument was null.
+ */
+#define ROXTRACT_ERR_NULL -1
+
+/**
+ * The ROM image is an invalid size.
+ */
+#define ROXTRACT_ERR_INVALID_SIZE -2
+
+/**
+ * The requested module does not exist.
+ */
+#define ROXTRACT_ERR_NO_MODULE -3
+
+/**
+ * The module could not be decoded.
+ */
+#define ROXTRACT_ERR_DECODE -4
+
+/**
+ * The output buffer is too small; the name was truncated.
+ */
+#define ROXTRACT_ERR_TRUNCATED -5
+
+/**
+ * The ROM image could not be loaded for some other reason.
+ */
+#define ROXTRACT_ERR_LOAD -6
+
+/**
+ * An opaque handle to a loaded ROM image.
+ */
+typedef struct RoxtractRom RoxtractRom;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Loads a ROM image from memory, storing a new handle in `*out`.
+ *
+ * The image bytes are copied, so `data` need not outlive the handle. On failure, `*out` is set
+ * to null. A successful handle must be released with [`roxtract_free`].
+ */
+int roxtract_load(const uint8_t *data, size_t len, RoxtractRom **out);
+
+/**
+ * Returns the number of modules in the ROM chain, or a negative status code.
+ */
+int roxtract_module_count(const RoxtractRom *rom);
+
+/**
+ * Copies the title of module `index` into `buf` as a NUL-terminated string.
+ *
+ * If `buf_len` is too small, the title is truncated (still NUL-terminated, if `buf_len` is
+ * non-zero) and `ROXTRACT_ERR_TRUNCATED` is returned.
+ */
+int roxtract_module_name(const RoxtractRom *rom, size_t index, uint8_t *buf, size_t buf_len);
+
+/**
+ * Releases a handle from [`roxtract_load`]. Passing null is a no-op.
+ */
+void roxtract_free(RoxtractRom *rom);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* ROXTRACT_H */
@@ -1,10 +1,21 @@
 use std::{
 	borrow::Borrow,
+	iter::FusedIterator,
 	mem::transmute,
 	ops::Range,
 	slice::from_raw_parts,
 };
 
+/// The byte order of multi-byte values in a ROM image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Endian {
+	/// Least significant byte first, as used by Acorn's ARM machines.
+	#[default]
+	Little,
+	/// Most significant byte first.
+	Big,
+}
+
 /// A thin wrapper around a byte slice, providing fallible, copying, 32-bit access operations.
 /// The underlying slice is no larger than `i32::MAX`.
 #[repr(transparent)]
@@ -22,7 +33,7 @@ impl Slice32 {
 		if src.len() > Self::SIZE_LIMIT { return None; }
 		Some(unsafe {
 			// SAFETY: we're casting to a transparent wrapper type
-			transmute(src)
+			transmute::<&[u8], &Slice32>(src)
 		})
 	}
 
@@ -33,10 +44,16 @@ impl Slice32 {
 
 		Ok(unsafe {
 			// SAFETY: we're casting to a transparent wrapper type, via Box
-			transmute(src)
+			transmute::<Box<[u8]>, Box<Slice32>>(src)
 		})
 	}
 
+	/// Copies `self` into a new `Box` allocation.
+	pub fn to_boxed(&self) -> Box<Slice32> {
+		Slice32::new_boxed(Box::from(&self.0))
+			.unwrap_or_else(|_| unreachable!("copy is the same length as `self`"))
+	}
+
 	/// Constructs a new `Slice32` without verifying its length.
 	///
 	/// # Safety
@@ -56,20 +73,109 @@ impl Slice32 {
 		self.0.get(idx as usize).copied()
 	}
 
-	/// Reads a word at the given index.
+	/// Returns the byte at the given index, or `None` if out of range.
+	///
+	/// This is the same as [`read_byte`](Self::read_byte), named to match `[u8]::get`.
+	#[inline]
+	pub fn get(&self, idx: u32) -> Option<u8> {
+		self.read_byte(idx)
+	}
+
+	/// Reads a little-endian word at the given index.
 	///
 	/// This memory access does _not_ need to be aligned, physically or logically.
+	#[inline]
 	pub fn read_word(&self, idx: u32) -> Option<u32> {
-		if idx.saturating_add(4) > self.len() {
-			return None;
+		self.read_word_endian(idx, Endian::Little)
+	}
+
+	/// Reads a word of the given byte order at the given index.
+	///
+	/// This memory access does _not_ need to be aligned, physically or logically.
+	pub fn read_word_endian(&self, idx: u32, endian: Endian) -> Option<u32> {
+		let bytes = self.read_array::<4>(idx)?;
+		Some(match endian {
+			Endian::Little => u32::from_le_bytes(bytes),
+			Endian::Big => u32::from_be_bytes(bytes),
+		})
+	}
+
+	/// Returns `true` if the last four bytes of `self` are the little-endian encoding of
+	/// `value`.
+	///
+	/// Returns `false` for slices shorter than four bytes.
+	pub fn ends_with_word(&self, value: u32) -> bool {
+		self.len().checked_sub(4).and_then(|at| self.read_word(at)) == Some(value)
+	}
+
+	/// Returns `true` if every byte of `self` is `byte`, such as a run of fill or padding.
+	///
+	/// Vacuously returns `true` for an empty slice.
+	pub fn is_all(&self, byte: u8) -> bool {
+		// compare eight bytes at a time, rather than byte by byte
+		let pattern = u64::from_ne_bytes([byte; 8]);
+		let mut chunks = self.0.chunks_exact(8);
+		chunks.by_ref().all(|c| u64::from_ne_bytes(c.try_into().unwrap()) == pattern)
+			&& chunks.remainder().iter().all(|&b| b == byte)
+	}
+
+	/// Returns the number of times `b` occurs in `self`.
+	pub fn count_byte(&self, b: u8) -> u32 {
+		// summing fixed-size chunks into a narrow counter lets the compiler vectorise the inner
+		// loop; 255 bytes per chunk can't overflow a u8
+		let mut chunks = self.0.chunks_exact(255);
+		let mut count = 0u32;
+		for chunk in chunks.by_ref() {
+			count += chunk.iter().fold(0u8, |n, &x| n + (x == b) as u8) as u32;
 		}
+		count + chunks.remainder().iter().filter(|&&x| x == b).count() as u32
+	}
 
-		Some(unsafe {
-			// SAFETY: we know the slice is big enough, and we don't require u32 alignment
-			self.0.as_ptr().add(idx as usize).cast::<u32>().read_unaligned()
+	/// Views `self` as a slice of words, if it's suitably placed in memory: its start must be
+	/// 4-byte aligned, and its length a multiple of 4.
+	///
+	/// This allows faster bulk word access than [`read_word`](Self::read_word). Callers should
+	/// fall back to that when this returns `None`, which can happen even for a whole ROM image:
+	/// a memory-mapped file, or a `Rom` created with a skipped prefix, needn't be aligned.
+	///
+	/// The words are in the host's byte order; use [`u32::from_le`] or [`u32::from_be`] to get
+	/// the values the ROM image holds.
+	pub fn as_u32_slice(&self) -> Option<&[u32]> {
+		let (head, words, tail) = unsafe {
+			// SAFETY: every bit pattern is a valid u32
+			self.0.align_to::<u32>()
+		};
+		(head.is_empty() && tail.is_empty()).then_some(words)
+	}
+
+	/// Reads a little-endian halfword at the given index.
+	///
+	/// This memory access does _not_ need to be aligned, physically or logically.
+	#[inline]
+	pub fn read_u16(&self, idx: u32) -> Option<u16> {
+		self.read_u16_endian(idx, Endian::Little)
+	}
+
+	/// Reads a halfword of the given byte order at the given index.
+	///
+	/// This memory access does _not_ need to be aligned, physically or logically.
+	pub fn read_u16_endian(&self, idx: u32, endian: Endian) -> Option<u16> {
+		let bytes = self.read_array::<2>(idx)?;
+		Some(match endian {
+			Endian::Little => u16::from_le_bytes(bytes),
+			Endian::Big => u16::from_be_bytes(bytes),
 		})
 	}
 
+	/// Copies `N` bytes starting at the given index, such as a fixed-width tag or signature.
+	///
+	/// Returns `None` if any of those bytes are out of range. Like the other reads, this does
+	/// _not_ need to be aligned.
+	#[inline]
+	pub fn read_array<const N: usize>(&self, idx: u32) -> Option<[u8; N]> {
+		self.0.get(idx as usize ..)?.get(.. N)?.try_into().ok()
+	}
+
 	/// Subslices `self` by the given range.
 	///
 	/// Returns `None` if the requested slice is not in range.
@@ -92,10 +198,53 @@ impl Slice32 {
 		self.subslice(new_start..(self.len()))
 	}
 
+	/// Subslices `self` to the `len` bytes starting at `start`.
+	///
+	/// Returns `None` if `start + len` overflows, or the requested slice is not in range.
+	#[inline]
+	pub fn subslice_len(&self, start: u32, len: u32) -> Option<&Self> {
+		self.subslice(start..start.checked_add(len)?)
+	}
+
+	/// Returns `true` if `needle` occurs in `self` at exactly `pos`.
+	///
+	/// Returns `false` if `needle` would extend beyond the end of `self`.
+	#[inline]
+	pub fn matches_at(&self, pos: u32, needle: &Slice32) -> bool {
+		self.subslice_len(pos, needle.len()) == Some(needle)
+	}
+
+	/// Splits `self` into two at byte index `mid`.
+	///
+	/// Returns `None` if `mid` is beyond the end of the slice.
+	pub fn split_at(&self, mid: u32) -> Option<(&Self, &Self)> {
+		let (head, tail) = self.0.split_at_checked(mid as usize)?;
+		Some(unsafe {
+			// SAFETY: both halves are subslices of `self`, so meet length criterion
+			(Slice32::new_unchecked(head), Slice32::new_unchecked(tail))
+		})
+	}
+
+	/// Splits `self` into two after `word_index` words, i.e. at byte index `word_index * 4`.
+	///
+	/// Returns `None` if that is beyond the end of the slice.
+	pub fn split_at_word(&self, word_index: u32) -> Option<(&Self, &Self)> {
+		self.split_at(word_index.checked_mul(4)?)
+	}
+
 	/// Returns `true` if `self` is an empty slice.
 	#[inline]
 	pub const fn is_empty(&self) -> bool { self.0.is_empty() }
 
+	/// Returns `self` truncated to a multiple of 4 bytes, dropping any trailing partial word.
+	#[inline]
+	pub fn word_aligned(&self) -> &Self {
+		unsafe {
+			// SAFETY: truncation can only shrink the slice, so length criterion is still met
+			Slice32::new_unchecked(&self.0[.. round_down_to_word(self.len()) as usize])
+		}
+	}
+
 	/// Returns the first byte in the slice, if it isn't empty.
 	#[inline]
 	pub const fn first(&self) -> Option<u8> {
@@ -114,12 +263,48 @@ impl Slice32 {
 		}))
 	}
 
+	/// Splits the slice after the first `n` bytes, returning them and the remainder.
+	///
+	/// Returns `None` if `n` is beyond the end of the slice. This is the same as
+	/// [`split_at`](Self::split_at), named for peeling a fixed-size prefix.
+	#[inline]
+	pub fn split_first_n(&self, n: u32) -> Option<(&Slice32, &Slice32)> {
+		self.split_at(n)
+	}
+
+	/// Returns an iterator over the segments of `self` separated by `sep`, from the front.
+	///
+	/// As with `[u8]::split`, adjacent separators produce empty segments, and an empty slice
+	/// yields one empty segment.
+	pub fn split(&self, sep: u8) -> impl DoubleEndedIterator<Item = &Slice32> + '_ {
+		self.0.split(move |&b| b == sep).map(|seg| unsafe {
+			// SAFETY: every segment is a subslice of `self`, so meets length criterion
+			Slice32::new_unchecked(seg)
+		})
+	}
+
+	/// Returns an iterator over the segments of `self` separated by `sep`, from the back.
+	///
+	/// This yields the same segments as [`split`](Self::split), in reverse order.
+	pub fn rsplit(&self, sep: u8) -> impl DoubleEndedIterator<Item = &Slice32> + '_ {
+		self.split(sep).rev()
+	}
+
+	/// Splits a C-string from the start of `self`, returning it (without its terminator) and the
+	/// remainder after the terminator.
+	///
+	/// Returns `None` if no terminator was found.
+	pub fn take_cstr(&self) -> Option<(&Self, &Self)> {
+		let s = self.cstr()?;
+		Some((s, self.subslice_from(s.len() + 1)?))
+	}
+
 	/// Interprets the start of `self` as being the first byte of a C-string, returning the rest.
 	///
 	/// Returns `None` if no terminator was found.
 	pub fn cstr(&self) -> Option<&Self> {
 		let mut n = 0;
-		while self.read_byte(n)? != 0 { n += 1; }
+		while self.get(n)? != 0 { n += 1; }
 		Some(unsafe {
 			// SAFETY: we've checked every byte in the slice, and also know the terminator is there
 			// we also won't pass a bogus range
@@ -127,6 +312,66 @@ impl Slice32 {
 		})
 	}
 
+	/// Returns an iterator over a table of consecutive C-strings at the start of `self`.
+	///
+	/// By default, iteration stops at the first empty string (as in the double-NUL convention
+	/// that ends many RISC OS tables) or when no terminated string remains. Use
+	/// [`CStrs::keep_empty`] to yield empty strings too, stopping only at the end of the data.
+	pub fn cstrs(&self) -> CStrs<'_> {
+		CStrs { rem: self, keep_empty: false }
+	}
+
+	/// Returns the length of the C-string at the start of `self`, including its terminator.
+	///
+	/// This is how far to advance past the string when walking a table of them. Returns `None`
+	/// if no terminator was found.
+	pub fn cstr_len(&self) -> Option<u32> {
+		self.cstr().map(|s| s.len() + 1)
+	}
+
+	/// Decodes the slice as Latin-1 (ISO 8859-1), which maps every byte to a `char`.
+	///
+	/// RISC OS uses Latin-1 by default, so this never fails.
+	pub fn to_latin1_string(&self) -> String {
+		self.0.iter().map(|&b| b as char).collect()
+	}
+
+	/// Computes the CRC32 (as used by zip, PNG, etc.) of the slice contents.
+	pub fn crc32(&self) -> u32 {
+		let mut hasher = crc_any::CRCu32::crc32();
+		hasher.digest(&self.0);
+		hasher.get_crc()
+	}
+
+	/// Returns the number of leading bytes that `self` and `other` have in common.
+	pub fn common_prefix_len(&self, other: &Slice32) -> u32 {
+		self.0.iter().zip(&other.0).take_while(|(a, b)| a == b).count() as u32
+	}
+
+	/// Returns the number of positions at which `self` and `other` hold the same byte, over the
+	/// length of the shorter slice.
+	pub fn count_matching_bytes(&self, other: &Slice32) -> u32 {
+		self.0.iter().zip(&other.0).filter(|(a, b)| a == b).count() as u32
+	}
+
+	/// Returns a subslice with all leading copies of `byte` removed.
+	pub fn trim_start_matches(&self, byte: u8) -> &Self {
+		let start = self.0.iter().position(|&b| b != byte).unwrap_or(self.0.len());
+		unsafe {
+			// SAFETY: `start` is no greater than our length
+			self.subslice_unchecked(start as u32 .. self.len())
+		}
+	}
+
+	/// Returns a subslice with all trailing copies of `byte` removed.
+	pub fn trim_end_matches(&self, byte: u8) -> &Self {
+		let end = self.0.iter().rposition(|&b| b != byte).map_or(0, |n| n + 1);
+		unsafe {
+			// SAFETY: `end` is no greater than our length
+			self.subslice_unchecked(0 .. end as u32)
+		}
+	}
+
 
 	unsafe fn subslice_unchecked(&self, range: Range<u32>) -> &Self {
 		unsafe {
@@ -141,6 +386,78 @@ impl Slice32 {
 	}
 }
 
+/// An iterator over consecutive C-strings, returned by [`Slice32::cstrs`].
+///
+/// Each string is yielded without its terminator.
+#[derive(Debug, Clone)]
+pub struct CStrs<'a> {
+	rem: &'a Slice32,
+	keep_empty: bool,
+}
+
+impl<'a> CStrs<'a> {
+	/// Yields empty strings rather than stopping at them.
+	pub fn keep_empty(self) -> Self {
+		CStrs { keep_empty: true, ..self }
+	}
+
+	/// Returns the data following the last string yielded.
+	pub fn remainder(&self) -> &'a Slice32 { self.rem }
+}
+
+impl<'a> Iterator for CStrs<'a> {
+	type Item = &'a Slice32;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let (s, rem) = self.rem.take_cstr()?;
+		if s.is_empty() && !self.keep_empty {
+			return None;
+		}
+		self.rem = rem;
+		Some(s)
+	}
+}
+
+impl<'a> FusedIterator for CStrs<'a> { }
+
+/// Rounds `n` down to the nearest multiple of 4.
+#[inline]
+pub const fn round_down_to_word(n: u32) -> u32 { n & !3 }
+
+/// Rounds `n` up to the nearest multiple of 4, or returns `None` if that would overflow.
+#[inline]
+pub const fn round_up_to_word(n: u32) -> Option<u32> {
+	match n.checked_add(3) {
+		Some(n) => Some(round_down_to_word(n)),
+		None => None,
+	}
+}
+
+/// Decodes an ARM `B` or `BL` instruction located at address `pc`, returning its target.
+///
+/// The condition field must not be `0b1111` (the unconditional instruction space, which holds
+/// `BLX` on later architectures). The low 24 bits are sign-extended and scaled to a byte offset,
+/// then added to `pc + 8`, as the PC reads two instructions ahead of the one executing.
+///
+/// Returns `None` if `instr` is not a branch, or the target doesn't fit in a `u32`.
+pub const fn decode_arm_branch(instr: u32, pc: u32) -> Option<u32> {
+	if instr >> 28 == 0xf || (instr >> 25) & 7 != 0b101 {
+		return None;
+	}
+
+	let offset = ((instr << 8) as i32) >> 6;
+	match pc.checked_add(8) {
+		Some(base) => base.checked_add_signed(offset),
+		None => None,
+	}
+}
+
+impl Clone for Box<Slice32> {
+	fn clone(&self) -> Self {
+		self.to_boxed()
+	}
+}
+
 impl Borrow<[u8]> for Slice32 {
 	#[inline(always)]
 	fn borrow(&self) -> &[u8] {
@@ -148,13 +465,34 @@ impl Borrow<[u8]> for Slice32 {
 	}
 }
 
-impl<'a> Borrow<[u8]> for &'a Slice32 {
+impl Borrow<[u8]> for &Slice32 {
 	#[inline(always)]
 	fn borrow(&self) -> &[u8] {
 		&self.0
 	}
 }
 
+impl PartialEq<[u8]> for Slice32 {
+	#[inline]
+	fn eq(&self, other: &[u8]) -> bool {
+		self.0 == *other
+	}
+}
+
+impl PartialEq<&[u8]> for Slice32 {
+	#[inline]
+	fn eq(&self, other: &&[u8]) -> bool {
+		self.0 == **other
+	}
+}
+
+impl PartialEq<Slice32> for [u8] {
+	#[inline]
+	fn eq(&self, other: &Slice32) -> bool {
+		*self == other.0
+	}
+}
+
 impl AsRef<[u8]> for Slice32 {
 	fn as_ref(&self) -> &[u8] {
 		&self.0
@@ -178,6 +516,8 @@ mod uat {
 		]) };
 
 		assert_eq!(Some(b'e'), DATA.read_byte(1));
+		assert_eq!(Some(b'e'), DATA.get(1));
+		assert_eq!(None, DATA.get(DATA.len()));
 		assert_eq!(Some(0x19), DATA.read_word(8));
 		assert_eq!(None, DATA.read_word(1<<20));
 
@@ -192,5 +532,250 @@ mod uat {
 		// cstring
 		assert_eq!(Some(&DATA.as_ref()[..6]), DATA.cstr().map(AsRef::as_ref));
 		assert_eq!(Some(0), DATA.subslice_from(6).and_then(Slice32::cstr).map(Slice32::len));
+		assert_eq!(Some(7), DATA.cstr_len());
+		assert_eq!(Some(1), DATA.subslice_from(6).and_then(Slice32::cstr_len));
+		assert_eq!(None, DATA.subslice_from(16).and_then(Slice32::cstr_len));
+	}
+
+	#[test]
+	fn trim_matches() {
+		static DATA: &Slice32 = unsafe { Slice32::new_unchecked(b"\xff\xffdata\0\xff\xff\xff") };
+		assert_eq!(DATA.trim_end_matches(0xff).as_ref(), b"\xff\xffdata\0");
+		assert_eq!(DATA.trim_start_matches(0xff).as_ref(), b"data\0\xff\xff\xff");
+
+		// no padding: unchanged
+		assert_eq!(DATA.trim_end_matches(b'!'), DATA);
+		assert_eq!(DATA.trim_start_matches(b'!'), DATA);
+
+		// all padding: empty
+		let fill = Slice32::new(&[0u8; 8]).unwrap();
+		assert!(fill.trim_end_matches(0).is_empty());
+		assert!(fill.trim_start_matches(0).is_empty());
+	}
+
+	#[test]
+	fn decode_arm_branch() {
+		use super::decode_arm_branch as decode;
+
+		assert_eq!(decode(0xea000006, 0), Some(0x20)); // B &20
+		assert_eq!(decode(0xeafffffe, 0x100), Some(0x100)); // B . (infinite loop)
+		assert_eq!(decode(0xebfffffc, 0x100), Some(0xf8)); // BL backwards
+		assert_eq!(decode(0x0a000000, 0x40), Some(0x48)); // BEQ, next but one
+		assert_eq!(decode(0xeafffffc, 0), None); // target before address 0
+
+		assert_eq!(decode(0xe1a00000, 0), None); // MOV R0, R0
+		assert_eq!(decode(0xe59ff000, 0), None); // LDR PC, [PC, #0]
+		assert_eq!(decode(0xfa000000, 0), None); // BLX
+	}
+
+	#[test]
+	fn eq_bytes() {
+		let title = Slice32::new(b"UtilityModule").unwrap();
+		assert!(*title == b"UtilityModule"[..]);
+		assert!(title == &b"UtilityModule"[..]);
+		let literal: &[u8] = b"UtilityModule";
+		assert!(*title == literal);
+		assert!(b"UtilityModule"[..] == *title);
+		assert!(*title != b"Utility"[..]);
+	}
+
+	#[test]
+	fn word_alignment() {
+		assert_eq!(round_down_to_word(0), 0);
+		assert_eq!(round_down_to_word(7), 4);
+		assert_eq!(round_down_to_word(8), 8);
+		assert_eq!(round_down_to_word(u32::MAX), u32::MAX - 3);
+
+		assert_eq!(round_up_to_word(0), Some(0));
+		assert_eq!(round_up_to_word(5), Some(8));
+		assert_eq!(round_up_to_word(8), Some(8));
+		assert_eq!(round_up_to_word(u32::MAX - 3), Some(u32::MAX - 3));
+		assert_eq!(round_up_to_word(u32::MAX - 2), None);
+
+		let s = Slice32::new(b"abcdefg").unwrap();
+		assert_eq!(s.word_aligned(), b"abcd".as_slice());
+		assert_eq!(s.word_aligned().word_aligned(), b"abcd".as_slice());
+		assert!(Slice32::new(b"abc").unwrap().word_aligned().is_empty());
+	}
+
+	#[test]
+	fn endian_reads() {
+		let s = Slice32::new(&[0x01, 0x02, 0x03, 0x04, 0x05]).unwrap();
+		assert_eq!(s.read_word(0), Some(0x04030201));
+		assert_eq!(s.read_word_endian(0, Endian::Big), Some(0x01020304));
+		assert_eq!(s.read_word_endian(1, Endian::Big), Some(0x02030405));
+		assert_eq!(s.read_word_endian(2, Endian::Big), None);
+		assert_eq!(s.read_u16(3), Some(0x0504));
+		assert_eq!(s.read_u16_endian(3, Endian::Big), Some(0x0405));
+		assert_eq!(s.read_u16(4), None);
+		assert_eq!(s.read_u16(u32::MAX), None);
+	}
+
+	#[test]
+	fn split() {
+		let s = Slice32::new(b"Resources\0Path\0\0Name").unwrap();
+		let fwd: Vec<&[u8]> = s.split(0).map(AsRef::as_ref).collect();
+		assert_eq!(fwd, [&b"Resources"[..], b"Path", b"", b"Name"]);
+
+		let rev: Vec<&[u8]> = s.rsplit(0).map(AsRef::as_ref).collect();
+		assert_eq!(rev, [&b"Name"[..], b"", b"Path", b"Resources"]);
+		assert_eq!(s.rsplit(0).next(), Some(Slice32::new(b"Name").unwrap()));
+
+		let empty = Slice32::new(b"").unwrap();
+		assert_eq!(empty.split(0).count(), 1);
+		assert_eq!(Slice32::new(b"\0").unwrap().rsplit(0).count(), 2);
+	}
+
+	#[test]
+	fn cstrs() {
+		let s = Slice32::new(b"Load\0Save\0\0Spare\0tail").unwrap();
+		let table: Vec<&[u8]> = s.cstrs().map(AsRef::as_ref).collect();
+		assert_eq!(table, [&b"Load"[..], b"Save"]);
+
+		let mut all = s.cstrs().keep_empty();
+		let all_vec: Vec<&[u8]> = all.by_ref().map(AsRef::as_ref).collect();
+		assert_eq!(all_vec, [&b"Load"[..], b"Save", b"", b"Spare"]);
+		assert_eq!(all.remainder(), b"tail".as_slice());
+
+		assert_eq!(Slice32::new(b"").unwrap().cstrs().count(), 0);
+	}
+
+	#[test]
+	fn similarity() {
+		let a = Slice32::new(b"RISC OS 3.11").unwrap();
+		let b = Slice32::new(b"RISC OS 3.10 (patched)").unwrap();
+		assert_eq!(a.common_prefix_len(b), 11);
+		assert_eq!(a.count_matching_bytes(b), 11);
+		assert_eq!(b.count_matching_bytes(a), 11);
+
+		let c = Slice32::new(b"XISC OS 3.11").unwrap();
+		assert_eq!(a.common_prefix_len(c), 0);
+		assert_eq!(a.count_matching_bytes(c), 11);
+		assert_eq!(a.common_prefix_len(a), a.len());
+		assert_eq!(a.count_matching_bytes(Slice32::new(b"").unwrap()), 0);
+	}
+
+	#[test]
+	fn split_at() {
+		let s = Slice32::new(b"headpayload").unwrap();
+		let (head, tail) = s.split_at(4).unwrap();
+		assert_eq!(head, b"head".as_slice());
+		assert_eq!(tail, b"payload".as_slice());
+		assert_eq!(s.split_at(11).map(|(_, t)| t.len()), Some(0));
+		assert_eq!(s.split_at(12), None);
+
+		assert_eq!(s.split_at_word(1), s.split_at(4));
+		assert_eq!(s.split_at_word(2).map(|(h, _)| h.len()), Some(8));
+		assert_eq!(s.split_at_word(3), None);
+		assert_eq!(s.split_at_word(u32::MAX / 2), None);
+	}
+
+	#[test]
+	fn ends_with_word() {
+		let s = Slice32::new(b"\0\x78\x56\x34\x12").unwrap();
+		assert!(s.ends_with_word(0x12345678));
+		assert!(!s.ends_with_word(0x56347800));
+		assert!(!Slice32::new(b"\x78\x56\x34").unwrap().ends_with_word(0x345678));
+		assert!(!Slice32::new(b"").unwrap().ends_with_word(0));
+	}
+
+	#[test]
+	fn subslice_len() {
+		let s = Slice32::new(b"hello world").unwrap();
+		assert_eq!(s.subslice_len(6, 5).unwrap(), b"world".as_slice());
+		assert_eq!(s.subslice_len(11, 0).unwrap(), b"".as_slice());
+		assert_eq!(s.subslice_len(6, 6), None);
+		assert_eq!(s.subslice_len(4, u32::MAX), None);
+		assert_eq!(s.subslice_len(u32::MAX, 1), None);
+	}
+
+	#[test]
+	fn is_all() {
+		let mut fill = vec![0xffu8; 0x1003];
+		assert!(Slice32::new(&fill).unwrap().is_all(0xff));
+		assert!(!Slice32::new(&fill).unwrap().is_all(0));
+		assert!(Slice32::new(b"").unwrap().is_all(0));
+
+		fill[0x1002] = 0; // in the tail after the last whole chunk
+		assert!(!Slice32::new(&fill).unwrap().is_all(0xff));
+		fill[0x1002] = 0xff;
+		fill[0x800] = 0xfe;
+		assert!(!Slice32::new(&fill).unwrap().is_all(0xff));
+	}
+
+	#[test]
+	fn read_array() {
+		let s = Slice32::new(b"MODULE#\0").unwrap();
+		assert_eq!(s.read_array::<7>(0), Some(*b"MODULE#"));
+		assert_eq!(s.read_array::<4>(4), Some(*b"LE#\0"));
+		assert_eq!(s.read_array::<4>(5), None);
+		assert_eq!(s.read_array::<0>(8), Some([]));
+		assert_eq!(s.read_array::<0>(9), None);
+		assert_eq!(s.read_array::<1>(u32::MAX), None);
+	}
+
+	#[test]
+	fn matches_at() {
+		let s = Slice32::new(b"abcMODULE#\0").unwrap();
+		let marker = Slice32::new(b"MODULE#\0").unwrap();
+		assert!(s.matches_at(3, marker));
+		assert!(!s.matches_at(2, marker));
+		assert!(!s.matches_at(4, marker));
+		assert!(!s.matches_at(u32::MAX, marker));
+		assert!(s.matches_at(11, Slice32::new(b"").unwrap()));
+	}
+
+	#[test]
+	fn count_byte() {
+		let mut mixed = vec![0xffu8; 0x400];
+		mixed[..0x100].fill(0);
+		mixed[0x3ff] = b'a';
+		let s = Slice32::new(&mixed).unwrap();
+		assert_eq!(s.count_byte(0xff), 0x2ff);
+		assert_eq!(s.count_byte(0), 0x100);
+		assert_eq!(s.count_byte(b'a'), 1);
+		assert_eq!(s.count_byte(b'b'), 0);
+		assert_eq!(Slice32::new(b"").unwrap().count_byte(0), 0);
+	}
+
+	#[test]
+	fn as_u32_slice() {
+		let words = [0x12345678u32.to_le(), 0x9abcdef0u32.to_le(), 0];
+		let bytes = unsafe {
+			// SAFETY: `words` is plain data, and outlives this view of it
+			std::slice::from_raw_parts(words.as_ptr().cast::<u8>(), 12)
+		};
+		let s = Slice32::new(bytes).unwrap();
+		let view = s.as_u32_slice().unwrap();
+		assert_eq!(view.len(), 3);
+		assert_eq!(u32::from_le(view[1]), 0x9abcdef0);
+
+		assert_eq!(s.subslice(4..12).unwrap().as_u32_slice().map(<[u32]>::len), Some(2));
+		assert_eq!(s.subslice(1..9).unwrap().as_u32_slice(), None);
+		assert_eq!(s.subslice(0..6).unwrap().as_u32_slice(), None);
+	}
+
+	#[test]
+	fn split_first_n() {
+		let s = Slice32::new(b"MODULE#\0kernel").unwrap();
+		let (marker, rest) = s.split_first_n(8).unwrap();
+		assert_eq!(marker, b"MODULE#\0".as_slice());
+		assert_eq!(rest, b"kernel".as_slice());
+		assert_eq!(s.split_first_n(0).map(|(h, t)| (h.len(), t.len())), Some((0, 14)));
+		assert_eq!(s.split_first_n(14).map(|(_, t)| t.len()), Some(0));
+		assert_eq!(s.split_first_n(15), None);
+	}
+
+	#[test]
+	fn take_cstr() {
+		let s = Slice32::new(b"Resources\0Path\0").unwrap();
+		let (first, rest) = s.take_cstr().unwrap();
+		assert_eq!(first, b"Resources".as_slice());
+		assert_eq!(rest, b"Path\0".as_slice());
+		let (second, rest) = rest.take_cstr().unwrap();
+		assert_eq!(second, b"Path".as_slice());
+		assert!(rest.is_empty());
+		assert_eq!(rest.take_cstr(), None);
+		assert_eq!(Slice32::new(b"Unterminated").unwrap().take_cstr(), None);
 	}
 }
@@ -0,0 +1,73 @@
+use std::{borrow::Borrow, path::Path};
+
+use crate::{Endian, Rom, RomConfig, RomLoadError, ROM_LIMIT};
+
+/// Configures how a [`Rom`] is loaded and interpreted.
+///
+/// For the common case, [`Rom::from_file`] and [`Rom::from_mem`] are equivalent to using a
+/// default builder.
+#[derive(Debug, Clone)]
+pub struct RomBuilder {
+	size_limit: u32,
+	skip: u32,
+	config: RomConfig,
+}
+
+impl Default for RomBuilder {
+	fn default() -> Self {
+		RomBuilder {
+			size_limit: ROM_LIMIT,
+			skip: 0,
+			config: RomConfig::default(),
+		}
+	}
+}
+
+impl RomBuilder {
+	/// Creates a builder with the default settings.
+	pub fn new() -> Self { Self::default() }
+
+	/// Sets the largest ROM image, in bytes, that will be accepted.
+	///
+	/// The default is 12 MiB, the size of the ROM area in the Archimedes memory map. Limits
+	/// above `i32::MAX` are reduced to `i32::MAX`.
+	pub fn size_limit(mut self, limit: u32) -> Self {
+		self.size_limit = limit.min(i32::MAX as u32);
+		self
+	}
+
+	/// Sets the logical address at which the ROM image is mapped.
+	///
+	/// The default is `0x03800000`, as used by Arthur and RISC OS 2 and 3.
+	pub fn logical_base(mut self, base: u32) -> Self {
+		self.config.logical_base = base;
+		self
+	}
+
+	/// Sets the byte order of words in the ROM image, such as module chain lengths and header
+	/// offsets.
+	///
+	/// The default is little-endian, as used by all Acorn hardware.
+	pub fn endianness(mut self, endian: Endian) -> Self {
+		self.config.endian = endian;
+		self
+	}
+
+	/// Sets a number of bytes to skip before the ROM image starts, such as a container header.
+	///
+	/// The default is 0. The size limit and alignment checks apply after skipping.
+	pub fn skip(mut self, skip: u32) -> Self {
+		self.skip = skip;
+		self
+	}
+
+	/// Loads a `Rom` owning its contents from a file.
+	pub fn open_file<P: AsRef<Path>>(&self, path: P) -> Result<Rom, RomLoadError> {
+		Rom::from_file_impl(path.as_ref(), self.skip as u64, self.size_limit, self.config)
+	}
+
+	/// Creates a `Rom` from some existing memory allocation containing a ROM image.
+	pub fn from_mem<M: Borrow<[u8]>>(&self, mem: M) -> Result<Rom<M>, RomLoadError> {
+		Rom::from_mem_impl(mem, self.skip, self.size_limit, self.config)
+	}
+}
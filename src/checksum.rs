@@ -0,0 +1,106 @@
+use std::borrow::Borrow;
+
+use crate::{Identification, KnownRiscOsVersion, Rom};
+
+/// A scheme by which a ROM image can be checked for corruption.
+///
+/// The checksums stored in the image footer are not yet understood for any catalogued
+/// version, so unless a [`ChecksumLayout`] is set with [`Rom::with_checksum_layout`], only
+/// images matching a catalogued version can be checked.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum ChecksumKind {
+	/// The image carries the version string of a catalogued version, so its CRC32 should
+	/// match the one recorded for that version.
+	Catalogued(&'static KnownRiscOsVersion),
+	/// The image footer holds a checksum, laid out as described. No algorithm for it is known
+	/// yet, so it can be [read](Rom::stored_checksum) but not verified.
+	Footer(ChecksumLayout),
+}
+
+/// Describes how the words at the end of a ROM image are used.
+///
+/// No layout is known for any catalogued version, so this only takes effect when set with
+/// [`Rom::with_checksum_layout`]; it is never derived from the detected version.
+///
+/// Counting back from the end of the image, the footer is made up of `checksum_words` words
+/// of checksum, then `size_words` words of size, then `reserved_words` words that are
+/// ignored. A layout only locates these words; how the checksum is computed isn't known, so
+/// [`Rom::verify_checksum`] can't check it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChecksumLayout {
+	/// The number of words holding the checksum.
+	pub checksum_words: u32,
+	/// The number of words holding the image size.
+	pub size_words: u32,
+	/// The number of words of the footer that are ignored.
+	pub reserved_words: u32,
+}
+
+/// The result of [`Rom::verify_checksum`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChecksumStatus {
+	/// The checksum matches.
+	Valid,
+	/// The checksum doesn't match; the image is corrupt or has been modified.
+	Invalid,
+	/// The image's checksum scheme isn't known, so it can't be verified either way.
+	UnknownScheme,
+}
+
+impl<M: Borrow<[u8]>> Rom<M> {
+	/// Sets the layout of the image footer, so that the checksum it holds can be
+	/// [read](Self::stored_checksum).
+	pub fn with_checksum_layout(mut self, layout: ChecksumLayout) -> Self {
+		self.config.checksum_layout = Some(layout);
+		self
+	}
+
+	/// Returns the layout of the image footer given to
+	/// [`with_checksum_layout`](Self::with_checksum_layout), if any.
+	pub fn checksum_layout(&self) -> Option<ChecksumLayout> {
+		self.config.checksum_layout
+	}
+
+	/// Returns the checksum stored in the image footer, according to the
+	/// [checksum layout](Self::checksum_layout).
+	///
+	/// Returns `None` if no layout has been set, the layout has no checksum words, or the
+	/// footer is longer than the image.
+	pub fn stored_checksum(&self) -> Option<u32> {
+		let layout = self.checksum_layout().filter(|l| l.checksum_words != 0)?;
+		let at = self.as_slice32().len().checked_sub(layout.checksum_words.checked_mul(4)?)?;
+		self.as_slice32().read_word_endian(at, self.config.endian)
+	}
+
+	/// Returns the scheme by which this image can be checked, or `None` if it isn't known.
+	///
+	/// A [checksum layout](Self::checksum_layout) takes precedence over the catalogue.
+	pub fn checksum_kind(&self) -> Option<ChecksumKind> {
+		if let Some(layout) = self.checksum_layout() {
+			return Some(ChecksumKind::Footer(layout));
+		}
+		match self.identify() {
+			Identification::Identified(v) | Identification::PatchedVariant(v) =>
+				Some(ChecksumKind::Catalogued(v)),
+			Identification::Unknown => None,
+		}
+	}
+
+	/// Checks the image against its [checksum scheme](Self::checksum_kind).
+	///
+	/// An image with no known scheme is reported as [`ChecksumStatus::UnknownScheme`], rather
+	/// than as invalid. So is one with a [checksum layout](Self::checksum_layout), since the
+	/// algorithm behind footer checksums isn't known.
+	pub fn verify_checksum(&self) -> ChecksumStatus {
+		if self.checksum_layout().is_some() {
+			return ChecksumStatus::UnknownScheme;
+		}
+		// identification has already compared the CRC32 against the catalogue
+		match self.identify() {
+			Identification::Identified(_) => ChecksumStatus::Valid,
+			Identification::PatchedVariant(_) => ChecksumStatus::Invalid,
+			Identification::Unknown => ChecksumStatus::UnknownScheme,
+		}
+	}
+}
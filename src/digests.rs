@@ -0,0 +1,83 @@
+use std::{borrow::Borrow, fmt::Write};
+
+use md5::Md5;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+use crate::Rom;
+
+impl<M: Borrow<[u8]>> Rom<M> {
+	/// Computes the SHA-256 digest of the whole ROM image.
+	///
+	/// Unlike [`crc32`](Self::crc32), this is suitable for identifying dumps in a catalogue
+	/// keyed by content.
+	pub fn sha256(&self) -> [u8; 32] {
+		Sha256::digest(self.as_slice()).into()
+	}
+
+	/// Computes the SHA-1 digest of the whole ROM image, as used by preservation databases
+	/// such as No-Intro and TOSEC.
+	pub fn sha1(&self) -> [u8; 20] {
+		Sha1::digest(self.as_slice()).into()
+	}
+
+	/// Computes the MD5 digest of the whole ROM image, for matching against older catalogues.
+	///
+	/// MD5 is broken as a cryptographic hash, so prefer [`sha256`](Self::sha256) for anything
+	/// but lookups in existing databases.
+	pub fn md5(&self) -> [u8; 16] {
+		Md5::digest(self.as_slice()).into()
+	}
+
+	/// Collects the size and digests of the ROM image that identify it in a No-Intro or TOSEC
+	/// `.dat` file.
+	pub fn datfile_entry(&self) -> DatEntry {
+		DatEntry {
+			size: self.as_slice32().len(),
+			crc32: self.crc32(),
+			md5: self.md5(),
+			sha1: self.sha1(),
+		}
+	}
+}
+
+/// The fields identifying a ROM image in a No-Intro or TOSEC `.dat` file, as returned by
+/// [`Rom::datfile_entry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatEntry {
+	/// The length of the ROM image, in bytes.
+	pub size: u32,
+	/// The CRC32 of the ROM image.
+	pub crc32: u32,
+	/// The MD5 digest of the ROM image.
+	pub md5: [u8; 16],
+	/// The SHA-1 digest of the ROM image.
+	pub sha1: [u8; 20],
+}
+
+impl DatEntry {
+	/// Renders the entry as a `<rom>` element in the Logiqx XML format used by `.dat` files,
+	/// with the given file name.
+	///
+	/// Digests are written in lowercase hexadecimal, and the name is escaped as needed.
+	pub fn to_xml(&self, name: &str) -> String {
+		let mut out = String::from("<rom name=\"");
+		for ch in name.chars() {
+			match ch {
+				'&' => out.push_str("&amp;"),
+				'<' => out.push_str("&lt;"),
+				'>' => out.push_str("&gt;"),
+				'"' => out.push_str("&quot;"),
+				c => out.push(c),
+			}
+		}
+		write!(out, "\" size=\"{}\" crc=\"{:08x}\" md5=\"{}\" sha1=\"{}\"/>",
+			self.size, self.crc32, hex(&self.md5), hex(&self.sha1)).unwrap();
+		out
+	}
+}
+
+/// Formats `bytes` as lowercase hexadecimal.
+fn hex(bytes: &[u8]) -> String {
+	bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
@@ -0,0 +1,91 @@
+use std::{borrow::Borrow, fmt::Write};
+
+use crate::Rom;
+
+impl<M: Borrow<[u8]>> Rom<M> {
+	/// Describes each module in the chain as a JSON array, for driving a disassembler's loader
+	/// script (e.g. to create labelled memory blocks in Ghidra or IDA).
+	///
+	/// Each element is an object with these fields:
+	///
+	/// - `name`: the module title, decoded as Latin-1, or `null` if it can't be read;
+	/// - `offset`: the offset of the module header within the ROM image;
+	/// - `length`: the module length in bytes, excluding the chain length word;
+	/// - `address`: the logical address of the module header, per
+	///   [`offset_to_address`](Self::offset_to_address);
+	/// - `entry`: the logical address of the module's start code, or `null` if it has none.
+	///
+	/// Numbers are written in decimal, as JSON has no hexadecimal literals.
+	pub fn export_regions_json(&self) -> String {
+		let mut out = String::from("[");
+		for (n, module) in self.module_chain().enumerate() {
+			let name = module.title().ok().map(|t| json_string(&t.to_latin1_string()));
+			let entry = module.resolve(0)
+				.and_then(|offset| self.offset_to_address(offset));
+
+			out.push_str(if n == 0 { "\n\t" } else { ",\n\t" });
+			write!(out, "{{\"name\": {}, \"offset\": {}, \"length\": {}, \"address\": {}, \
+				\"entry\": {}}}",
+				name.as_deref().unwrap_or("null"), module.offset(), module.data().len(),
+				json_number(self.offset_to_address(module.offset())), json_number(entry),
+			).unwrap();
+		}
+		out.push_str(if out.len() > 1 { "\n]" } else { "]" });
+		out
+	}
+
+	/// Describes each module in the chain as CSV, for pasting into a spreadsheet.
+	///
+	/// A header line is followed by one row per module, with these columns:
+	///
+	/// - `index`: the position of the module in the chain, counting from 0;
+	/// - `name`: the module title, decoded as Latin-1, or empty if it can't be read;
+	/// - `offset`: the offset of the module header within the ROM image;
+	/// - `length`: the module length in bytes, excluding the chain length word;
+	/// - `swi_base`: the base of the module's [SWI chunk](crate::Module::swi_chunk_base), or
+	///   empty if it has none.
+	///
+	/// Numbers are written in decimal, which spreadsheets read without any conversion.
+	pub fn module_table_csv(&self) -> String {
+		let mut out = String::from("index,name,offset,length,swi_base\n");
+		for (n, module) in self.module_chain().enumerate() {
+			let name = module.title().map(|t| csv_field(&t.to_latin1_string())).unwrap_or_default();
+			let swi_base = module.swi_chunk_base().map(|n| n.to_string()).unwrap_or_default();
+			writeln!(out, "{},{},{},{},{}",
+				n, name, module.offset(), module.data().len(), swi_base).unwrap();
+		}
+		out
+	}
+}
+
+/// Quotes and escapes `s` as a CSV field, if it contains anything that needs it.
+fn csv_field(s: &str) -> String {
+	if s.contains([',', '"', '\n', '\r']) {
+		format!("\"{}\"", s.replace('"', "\"\""))
+	} else {
+		s.to_owned()
+	}
+}
+
+/// Quotes and escapes `s` as a JSON string.
+fn json_string(s: &str) -> String {
+	let mut out = String::with_capacity(s.len() + 2);
+	out.push('"');
+	for ch in s.chars() {
+		match ch {
+			'"' => out.push_str("\\\""),
+			'\\' => out.push_str("\\\\"),
+			'\n' => out.push_str("\\n"),
+			'\t' => out.push_str("\\t"),
+			c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+			c => out.push(c),
+		}
+	}
+	out.push('"');
+	out
+}
+
+/// Formats an optional number as a JSON value.
+fn json_number(n: Option<u32>) -> String {
+	n.map_or_else(|| "null".to_owned(), |n| n.to_string())
+}
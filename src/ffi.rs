@@ -0,0 +1,219 @@
+//! C ABI over the core ROM queries.
+//!
+//! Every function returns one of the `ROXTRACT_*` status codes (or a count, where noted). The
+//! matching C header lives at `include/roxtract.h`, and can be regenerated with
+//! `cbindgen --config cbindgen.toml --output include/roxtract.h` from the crate root.
+
+use std::{
+	ffi::c_int,
+	ptr,
+	slice,
+};
+
+use crate::{Rom, RomLoadError};
+
+/// The call succeeded.
+pub const ROXTRACT_OK: c_int = 0;
+/// A required pointer argument was null.
+pub const ROXTRACT_ERR_NULL: c_int = -1;
+/// The ROM image is an invalid size.
+pub const ROXTRACT_ERR_INVALID_SIZE: c_int = -2;
+/// The requested module does not exist.
+pub const ROXTRACT_ERR_NO_MODULE: c_int = -3;
+/// The module could not be decoded.
+pub const ROXTRACT_ERR_DECODE: c_int = -4;
+/// The output buffer is too small; the name was truncated.
+pub const ROXTRACT_ERR_TRUNCATED: c_int = -5;
+/// The ROM image could not be loaded for some other reason.
+pub const ROXTRACT_ERR_LOAD: c_int = -6;
+
+/// An opaque handle to a loaded ROM image.
+pub struct RoxtractRom(Rom);
+
+/// Loads a ROM image from memory, storing a new handle in `*out`.
+///
+/// The image bytes are copied, so `data` need not outlive the handle. On failure, `*out` is set
+/// to null. A successful handle must be released with [`roxtract_free`].
+///
+/// # Safety
+///
+/// - `data` must point to `len` readable bytes (or be null when `len` is zero);
+/// - `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn roxtract_load(data: *const u8, len: usize, out: *mut *mut RoxtractRom)
+-> c_int {
+	if out.is_null() {
+		return ROXTRACT_ERR_NULL;
+	}
+	unsafe { *out = ptr::null_mut(); }
+
+	let bytes: &[u8] = match (data.is_null(), len) {
+		(true, 0) => &[],
+		(true, _) => return ROXTRACT_ERR_NULL,
+		(false, _) => unsafe {
+			// SAFETY: caller guarantees `len` readable bytes at `data`
+			slice::from_raw_parts(data, len)
+		},
+	};
+
+	match Rom::from_mem(Box::<[u8]>::from(bytes)) {
+		Ok(rom) => {
+			unsafe { *out = Box::into_raw(Box::new(RoxtractRom(rom))); }
+			ROXTRACT_OK
+		},
+		Err(RomLoadError::RomTooLarge { .. } | RomLoadError::RomMisaligned { .. }) =>
+			ROXTRACT_ERR_INVALID_SIZE,
+		Err(_) => ROXTRACT_ERR_LOAD,
+	}
+}
+
+/// Returns the number of modules in the ROM chain, or a negative status code.
+///
+/// # Safety
+///
+/// `rom` must be null or a live handle from [`roxtract_load`].
+#[no_mangle]
+pub unsafe extern "C" fn roxtract_module_count(rom: *const RoxtractRom) -> c_int {
+	let Some(rom) = (unsafe { rom.as_ref() }) else { return ROXTRACT_ERR_NULL };
+	rom.0.module_chain().count().try_into().unwrap_or(c_int::MAX)
+}
+
+/// Copies the title of module `index` into `buf` as a NUL-terminated string.
+///
+/// If `buf_len` is too small, the title is truncated (still NUL-terminated, if `buf_len` is
+/// non-zero) and `ROXTRACT_ERR_TRUNCATED` is returned.
+///
+/// # Safety
+///
+/// - `rom` must be null or a live handle from [`roxtract_load`];
+/// - `buf` must be null or valid for writes of `buf_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn roxtract_module_name(rom: *const RoxtractRom, index: usize,
+	buf: *mut u8, buf_len: usize) -> c_int {
+	let Some(rom) = (unsafe { rom.as_ref() }) else { return ROXTRACT_ERR_NULL };
+	if buf.is_null() {
+		return ROXTRACT_ERR_NULL;
+	}
+
+	let Some(module) = rom.0.module_chain().nth(index) else { return ROXTRACT_ERR_NO_MODULE };
+	let Ok(title) = module.title() else { return ROXTRACT_ERR_DECODE };
+	let title = title.as_ref();
+
+	if buf_len == 0 {
+		return ROXTRACT_ERR_TRUNCATED;
+	}
+
+	let copy_len = title.len().min(buf_len - 1);
+	unsafe {
+		// SAFETY: caller guarantees `buf_len` writable bytes; we write at most that many
+		ptr::copy_nonoverlapping(title.as_ptr(), buf, copy_len);
+		*buf.add(copy_len) = 0;
+	}
+
+	if copy_len < title.len() { ROXTRACT_ERR_TRUNCATED } else { ROXTRACT_OK }
+}
+
+/// Releases a handle from [`roxtract_load`]. Passing null is a no-op.
+///
+/// # Safety
+///
+/// `rom` must be null or a live handle from [`roxtract_load`], and must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn roxtract_free(rom: *mut RoxtractRom) {
+	if !rom.is_null() {
+		drop(unsafe { Box::from_raw(rom) });
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// A ROM image with a single module, titled `UtilityModule`.
+	fn rom_image() -> Vec<u8> {
+		let mut data = vec![0u8; 0x40];
+		data[0..4].copy_from_slice(&0xea000006u32.to_le_bytes()); // B &20
+		data[0x10..0x18].copy_from_slice(b"MODULE#\0");
+
+		let mut body = vec![0u8; 0x34];
+		body[0x04..0x08].copy_from_slice(&0x34u32.to_le_bytes());
+		body[0x10..0x14].copy_from_slice(&0x34u32.to_le_bytes());
+		body.extend_from_slice(b"UtilityModule\0\0\0");
+		data.extend_from_slice(&(body.len() as u32 + 4).to_le_bytes());
+		data.extend_from_slice(&body);
+		data.extend_from_slice(&[0; 4]);
+		data.extend_from_slice(&[0xff; 0x100]);
+		data.extend_from_slice(&[0; 12]);
+		data
+	}
+
+	fn load(data: &[u8]) -> *mut RoxtractRom {
+		let mut rom = ptr::null_mut();
+		assert_eq!(unsafe { roxtract_load(data.as_ptr(), data.len(), &mut rom) }, ROXTRACT_OK);
+		assert!(!rom.is_null());
+		rom
+	}
+
+	#[test]
+	fn load_null_pointers() {
+		let mut rom = ptr::null_mut();
+		assert_eq!(unsafe { roxtract_load(b"abcd".as_ptr(), 4, ptr::null_mut()) },
+			ROXTRACT_ERR_NULL);
+		assert_eq!(unsafe { roxtract_load(ptr::null(), 4, &mut rom) }, ROXTRACT_ERR_NULL);
+		assert!(rom.is_null());
+	}
+
+	#[test]
+	fn load_invalid_size() {
+		let mut rom = ptr::null_mut();
+		assert_eq!(unsafe { roxtract_load(b"abc".as_ptr(), 3, &mut rom) },
+			ROXTRACT_ERR_INVALID_SIZE);
+		assert!(rom.is_null());
+	}
+
+	#[test]
+	fn module_count() {
+		let rom = load(&rom_image());
+		assert_eq!(unsafe { roxtract_module_count(rom) }, 1);
+		assert_eq!(unsafe { roxtract_module_count(ptr::null()) }, ROXTRACT_ERR_NULL);
+		unsafe { roxtract_free(rom) };
+	}
+
+	#[test]
+	fn module_name() {
+		let rom = load(&rom_image());
+		let mut buf = [0xffu8; 16];
+
+		assert_eq!(unsafe { roxtract_module_name(rom, 0, buf.as_mut_ptr(), buf.len()) },
+			ROXTRACT_OK);
+		assert_eq!(&buf[..14], b"UtilityModule\0");
+
+		assert_eq!(unsafe { roxtract_module_name(rom, 1, buf.as_mut_ptr(), buf.len()) },
+			ROXTRACT_ERR_NO_MODULE);
+		assert_eq!(unsafe { roxtract_module_name(ptr::null(), 0, buf.as_mut_ptr(), buf.len()) },
+			ROXTRACT_ERR_NULL);
+		assert_eq!(unsafe { roxtract_module_name(rom, 0, ptr::null_mut(), buf.len()) },
+			ROXTRACT_ERR_NULL);
+		unsafe { roxtract_free(rom) };
+	}
+
+	#[test]
+	fn module_name_truncated() {
+		let rom = load(&rom_image());
+		let mut buf = [0xffu8; 8];
+
+		assert_eq!(unsafe { roxtract_module_name(rom, 0, buf.as_mut_ptr(), 0) },
+			ROXTRACT_ERR_TRUNCATED);
+		assert_eq!(buf, [0xff; 8]);
+
+		assert_eq!(unsafe { roxtract_module_name(rom, 0, buf.as_mut_ptr(), 6) },
+			ROXTRACT_ERR_TRUNCATED);
+		assert_eq!(&buf, b"Utili\0\xff\xff");
+		unsafe { roxtract_free(rom) };
+	}
+
+	#[test]
+	fn free_null() {
+		unsafe { roxtract_free(ptr::null_mut()) };
+	}
+}
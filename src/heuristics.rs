@@ -1,6 +1,7 @@
-use crate::bintrinsics::Slice32;
+use crate::bintrinsics::{Endian, Slice32};
 
 /// Metadata about a known RISC OS ROM image.
+#[derive(Debug)]
 #[non_exhaustive]
 pub struct KnownRiscOsVersion {
 	/// The OS version, in colloquial format (e.g. `RISC OS 3.11`).
@@ -19,66 +20,92 @@ static RISC_OS_311: KnownRiscOsVersion = KnownRiscOsVersion {
 	crc32: 0x54c0c963,
 };
 
+/// Every ROM image known to this crate.
+static KNOWN_VERSIONS: &[&KnownRiscOsVersion] = &[&RISC_OS_311];
+
+/// The result of comparing a ROM image against the known versions.
+#[derive(Debug, Clone, Copy)]
+pub enum Identification {
+	/// The image is an exact copy of a known version.
+	Identified(&'static KnownRiscOsVersion),
+	/// The image contains the version string of a known version, but its CRC differs; it is
+	/// most likely a patched or modified copy.
+	PatchedVariant(&'static KnownRiscOsVersion),
+	/// The image doesn't resemble any known version.
+	Unknown,
+}
+
 impl KnownRiscOsVersion {
 	/// Returns `true` if the byte data in `rom` matches `self`.
 	pub fn matches(&self, rom_data: &[u8]) -> bool {
+		self.name_matches(rom_data) && self.crc_matches(rom_data)
+	}
+
+	/// Returns a reference to a `KnownRiscOsVersion` object, if there is one that matches
+	/// the ROM image described in `rom_data`.
+	pub fn find(rom_data: &[u8]) -> Option<&'static KnownRiscOsVersion> {
+		match Self::identify(rom_data) {
+			Identification::Identified(v) => Some(v),
+			_ => None,
+		}
+	}
+
+	/// Compares the ROM image in `rom_data` against every known version, distinguishing exact
+	/// matches from images that only share a version string.
+	pub fn identify(rom_data: &[u8]) -> Identification {
+		let Some(version) = KNOWN_VERSIONS.iter().find(|v| v.name_matches(rom_data))
+		else { return Identification::Unknown };
+
+		if version.crc_matches(rom_data) {
+			Identification::Identified(version)
+		} else {
+			Identification::PatchedVariant(version)
+		}
+	}
+
+	fn name_matches(&self, rom_data: &[u8]) -> bool {
 		let Some(slice_end) = self.name_internal_pos.checked_add(self.name_internal.len() as u32)
 			.filter(|n| *n as usize <= rom_data.len())
 		else { return false };
 
-		if rom_data[self.name_internal_pos as usize .. slice_end as usize] != *self.name_internal {
-			return false;
-		}
+		rom_data[self.name_internal_pos as usize .. slice_end as usize] == *self.name_internal
+	}
 
+	fn crc_matches(&self, rom_data: &[u8]) -> bool {
 		let mut hasher = crc_any::CRCu32::crc32();
 		hasher.digest(rom_data);
 		hasher.get_crc() == self.crc32
 	}
-
-	/// Returns a reference to a `KnownRiscOsVersion` object, if there is one that matches
-	/// the ROM image described in `rom_data`.
-	pub fn find(rom_data: &[u8]) -> Option<&'static KnownRiscOsVersion> {
-		if RISC_OS_311.matches(rom_data) {
-			return Some(&RISC_OS_311);
-		}
-
-		None
-	}
 }
 
 struct WordCursor<'a> {
 	bytes: &'a Slice32,
 	cursor_rel: u32,
+	endian: Endian,
 }
 
 impl<'a> WordCursor<'a> {
-	pub fn new_start(bytes: &'a Slice32) -> Self {
-		Self::new(bytes, |_| 0)
+	pub fn new_start(bytes: &'a Slice32, endian: Endian) -> Self {
+		Self::new(bytes, endian, |_| 0)
 	}
 
-	pub fn new_end(bytes: &'a Slice32) -> Self {
-		Self::new(bytes, |b| b.len().saturating_sub(4))
+	pub fn new_end(bytes: &'a Slice32, endian: Endian) -> Self {
+		Self::new(bytes, endian, |b| b.len().saturating_sub(4))
 	}
 
-	fn new(bytes: &'a Slice32, make_start: impl FnOnce(&'a Slice32) -> u32) -> Self {
-		let bytes_words_only = bytes.subslice(0..(bytes.len() & !3)).unwrap();
+	fn new(bytes: &'a Slice32, endian: Endian, make_start: impl FnOnce(&'a Slice32) -> u32)
+	-> Self {
+		let bytes_words_only = bytes.word_aligned();
 
 		Self {
 			bytes: bytes_words_only,
 			cursor_rel: make_start(bytes_words_only),
+			endian,
 		}
 	}
 
 	pub fn current(&self) -> Option<u32> {
-		// ensure we have four bytes in range
-		if !matches!(self.cursor_rel.checked_add(4), Some(n) if n <= self.bytes.len()) {
-			return None; // index if out of range
-		}
-
-		Some(unsafe {
-			let ptr = self.bytes.as_ref().as_ptr().add(self.cursor_rel as usize).cast::<u32>();
-			core::ptr::read_unaligned(ptr as *const u32)
-		})
+		self.bytes.read_word_endian(self.cursor_rel, self.endian)
 	}
 
 	pub fn move_next(&mut self) {
@@ -92,38 +119,75 @@ impl<'a> WordCursor<'a> {
 	pub fn pos(&self) -> u32 { self.cursor_rel }
 }
 
+impl Slice32 {
+	/// Searches for the little-endian encoding of `value`, returning the byte offset of its
+	/// first occurrence.
+	///
+	/// If `aligned` is set, only word-aligned offsets are considered, which is faster.
+	pub fn find_word(&self, value: u32, aligned: bool) -> Option<u32> {
+		if !aligned {
+			return self.find(Slice32::new(&value.to_le_bytes()).unwrap());
+		}
+
+		let mut cursor = WordCursor::new_start(self, Endian::Little);
+		loop {
+			if cursor.current()? == value {
+				return Some(cursor.pos());
+			}
+			cursor.move_next();
+		}
+	}
+}
+
 /// Extension trait for searching through byte slices in application-specific ways.
 pub(crate) trait RomHeuristics {
 	/// Searches for `needle` in `self`, and returns a byte offset to it if found
 	fn find(&self, needle: &Slice32) -> Option<u32>;
 
-	/// Finds the byte offset a word in `self` that functions as an offset to a copy of `needle`
-	/// in `self`.
+	/// Returns the byte offset of every word in `self` that functions as an offset to
+	/// `target`, working backwards from `target`.
 	///
 	/// The `offset` parameter allows shifting the base of the relative addressing earlier by
-	/// some number of bytes.
-	fn find_offset_to(&self, needle: &Slice32, offset: u32) -> Option<u32>;
+	/// some number of bytes. Words are read in the byte order given by `endian`.
+	fn offsets_to(&self, target: u32, offset: u32, endian: Endian)
+	-> impl Iterator<Item = u32> + '_;
+
+	/// Returns the byte offsets of every non-overlapping copy of `needle` in `self`, in order.
+	fn find_all(&self, needle: &Slice32) -> Vec<u32>;
 }
 
 impl RomHeuristics for Slice32 {
-	fn find_offset_to(&self, needle: &Slice32, offset: u32) -> Option<u32> {
-		if self.len() < 4 { return None; }
-		let target = Self::find(self, needle)?;
-		let mut cursor = WordCursor::new_end(self.subslice(0..target)?);
-
-		loop {
-			let possible_start = cursor.pos().checked_sub(offset)?;
-			if cursor.current().and_then(|cc| possible_start.checked_add(cc)) == Some(target) {
-				return Some(possible_start);
+	fn offsets_to(&self, target: u32, offset: u32, endian: Endian)
+	-> impl Iterator<Item = u32> + '_ {
+		let mut cursor = self.subslice(0..target).map(|s| WordCursor::new_end(s, endian));
+		std::iter::from_fn(move || {
+			let cursor = cursor.as_mut()?;
+			loop {
+				// running off the start of `self` means no more words point at `target`
+				let current = cursor.current()?;
+				let possible_start = cursor.pos().checked_sub(offset)?;
+				cursor.move_prev();
+				if possible_start.checked_add(current) == Some(target) {
+					return Some(possible_start);
+				}
 			}
-			cursor.move_prev();
+		})
+	}
+
+	fn find_all(&self, needle: &Slice32) -> Vec<u32> {
+		let mut found = Vec::new();
+		let mut start = 0u32;
+		while let Some(pos) = self.subslice_from(start).and_then(|hs| hs.find(needle)) {
+			found.push(start + pos);
+			start += pos + needle.len();
 		}
+		found
 	}
 
 	fn find(&self, needle: &Slice32) -> Option<u32> {
 		let mut haystack = self;
 		if haystack.is_empty() { return None; }
-		let (&needle_first, needle_rem) = needle.split_first()?;
+		let needle_first = needle.first()?;
 
 		let mut hs_sub_start = 0u32;
 		loop {
@@ -136,10 +200,10 @@ impl RomHeuristics for Slice32 {
 				return None;
 			}
 
-			// first byte matches, compare remaining
-			if haystack.subslice(hs_range.clone()) == Some(needle_rem) {
-				// hs_range is relative to the subslice, not the original parameter
-				return Some(hs_range.start as u32 - 1 + hs_sub_start);
+			// first byte matches, compare the rest
+			if haystack.matches_at(start, needle) {
+				// `start` is relative to the subslice, not the original parameter
+				return Some(start + hs_sub_start);
 			}
 
 			haystack = haystack.subslice_from(hs_range.start).unwrap();
@@ -154,6 +218,8 @@ mod tests {
 
 	fn s(src: &[u8]) -> &Slice32 { Slice32::new(src).unwrap() }
 
+	const LE: Endian = Endian::Little;
+
 	#[test]
 	fn find() {
 		assert_eq!(s(b"abcdef").find(s(b"abc")), Some(0));
@@ -170,11 +236,51 @@ mod tests {
 	}
 
 	#[test]
-	fn find_offset_to() {
-		assert_eq!(s(b"\x08\0\0\0ABCDEFGH").find_offset_to(s(b"EFGH"), 0), Some(0));
-		assert_eq!(s(b"!!!!\x08\0\0\0ABCDEFGH").find_offset_to(s(b"EFGH"), 0), Some(4));
-		assert_eq!(s(b"!!!!\x04\0\0\0EFGH").find_offset_to(s(b"EFGH"), 0), Some(4));
-		assert_eq!(s(b"!!!!????ZERO\x08\0\0\0EFGH").find_offset_to(s(b"EFGH"), 4), Some(8));
+	fn identify() {
+		let pos = RISC_OS_311.name_internal_pos as usize;
+		let mut data = vec![0u8; pos + RISC_OS_311.name_internal.len() + 16];
+		assert!(matches!(KnownRiscOsVersion::identify(&data), Identification::Unknown));
+
+		data[pos ..][.. RISC_OS_311.name_internal.len()].copy_from_slice(RISC_OS_311.name_internal);
+		assert!(matches!(KnownRiscOsVersion::identify(&data),
+			Identification::PatchedVariant(v) if core::ptr::eq(v, &RISC_OS_311)));
+		assert!(KnownRiscOsVersion::find(&data).is_none());
+
+		assert!(matches!(KnownRiscOsVersion::identify(&data[.. pos + 4]), Identification::Unknown));
+	}
+
+	#[test]
+	fn find_word() {
+		let data = s(b"\0\x78\x56\x34\x12\0\0\0\x78\x56\x34\x12");
+		assert_eq!(data.find_word(0x12345678, false), Some(1));
+		assert_eq!(data.find_word(0x12345678, true), Some(8));
+		assert_eq!(data.find_word(0x00123456, false), Some(2));
+		assert_eq!(data.find_word(0x00123456, true), None);
+		assert_eq!(data.find_word(0xdeadbeef, false), None);
+
+		assert_eq!(s(b"\x01\0\0\0\x02\0").find_word(2, true), None); // trailing partial word
+		assert_eq!(s(b"").find_word(0, true), None);
+	}
+
+	#[test]
+	fn find_all() {
+		assert_eq!(s(b"abcabcab").find_all(s(b"abc")), [0, 3]);
+		assert_eq!(s(b"aaaa").find_all(s(b"aa")), [0, 2]);
+		assert_eq!(s(b"abcdef").find_all(s(b"xyz")), []);
+		assert_eq!(s(b"abc").find_all(s(b"")), []);
+	}
+
+	#[test]
+	fn offsets_to() {
+		assert_eq!(s(b"\x08\0\0\0ABCDEFGH").offsets_to(8, 0, LE).next(), Some(0));
+		assert_eq!(s(b"!!!!\x08\0\0\0ABCDEFGH").offsets_to(12, 0, LE).next(), Some(4));
+		assert_eq!(s(b"!!!!\x04\0\0\0EFGH").offsets_to(8, 0, LE).next(), Some(4));
+		assert_eq!(s(b"!!!!????ZERO\x08\0\0\0EFGH").offsets_to(16, 4, LE).next(), Some(8));
+		assert_eq!(s(b"!!!!\0\0\0\x04EFGH").offsets_to(8, 0, Endian::Big).next(), Some(4));
+		assert_eq!(s(b"!!!!\0\0\0\x04EFGH").offsets_to(8, 0, LE).next(), None);
+		assert_eq!(s(b"\x0c\0\0\0\x08\0\0\0EFGH").offsets_to(12, 0, LE).collect::<Vec<_>>(),
+			[4, 0]);
+		assert_eq!(s(b"\x08\0\0\0EFGH").offsets_to(0x100, 0, LE).next(), None);
 
 		assert_eq!(s(&[
 			b'o', b'f', b'f', b's', b'e', b't', b'!', b'!',
@@ -190,12 +296,12 @@ mod tests {
 			0,0,0,0, // swi table   r24 a2c
 			0,0,0,0, // swi code    r28 a30
 			b'M', b'o', b'd', b'u', b'l', b'e', 0 // r2c a34
-		]).find_offset_to(s(b"Module\0"), 0x10), Some(8));
+		]).offsets_to(0x34, 0x10, LE).next(), Some(8));
 	}
 
 	#[test]
-	fn find_offset_to_force_unaligned() {
-		#![allow(unstable_name_collisions)]
+	fn offsets_to_force_unaligned() {
+		#![allow(unstable_name_collisions, unused_imports)]
 		use sptr::Strict as _;
 
 		static DATA: &[u8] = b"\x08\0\0\0!no!HELLO\0";
@@ -206,6 +312,6 @@ mod tests {
 		};
 		data.copy_from_slice(DATA);
 		assert_ne!(data.as_ptr().addr() & 3, 0);
-		assert_eq!(s(data).find_offset_to(s(b"HELLO\0"), 0), Some(0));
+		assert_eq!(s(data).offsets_to(8, 0, LE).next(), Some(0));
 	}
 }
\ No newline at end of file
@@ -0,0 +1,117 @@
+use std::iter::FusedIterator;
+
+use crate::{round_up_to_word, Endian, Module, Slice32};
+
+/// Bit in a keyword's information word set if its help offset points to code, rather than text.
+const HELP_IS_CODE: u32 = 1 << 29;
+
+impl<'a> Module<'a> {
+	/// Returns an iterator over the module's help and command keyword table, from header word
+	/// 0x18.
+	///
+	/// Returns `None` if the module has no table (the word is zero, or the header is too short
+	/// to contain it).
+	pub fn keyword_table(&self) -> Option<KeywordTable<'a>> {
+		if self.header_len() <= 0x18 {
+			return None;
+		}
+		Some(KeywordTable {
+			bytes: self.bytes,
+			offset: self.offset,
+			pos: self.header_field(0x18)?,
+			endian: self.endian,
+		})
+	}
+}
+
+/// An entry in a module's help and command keyword table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeywordEntry<'a> {
+	/// A `*` command.
+	Command {
+		/// The command name.
+		name: &'a Slice32,
+		/// The absolute offset of the command's code within the ROM image.
+		code_offset: u32,
+		/// The information word, giving the parameter counts, the GSTrans map and the flags
+		/// (such as bit 31 for filing system commands, and bit 30 for `*Configure` keywords).
+		flags: u32,
+		/// The help text, if there is some (rather than none, or code to print it).
+		help: Option<&'a Slice32>,
+	},
+	/// A help topic, which can be given to `*Help` but isn't a command (its code offset is 0).
+	HelpText {
+		/// The topic name.
+		name: &'a Slice32,
+		/// The help text, if there is some (rather than none, or code to print it).
+		help: Option<&'a Slice32>,
+	},
+}
+
+impl<'a> KeywordEntry<'a> {
+	/// Returns the command or topic name.
+	pub fn name(&self) -> &'a Slice32 {
+		match *self {
+			KeywordEntry::Command { name, .. } | KeywordEntry::HelpText { name, .. } => name,
+		}
+	}
+}
+
+/// An iterator over a module's help and command keyword table.
+///
+/// Returned by [`Module::keyword_table`]. Each entry is a name, padded to a word boundary,
+/// followed by four words: the code offset, the information word, the invalid syntax message
+/// offset and the help offset. The table ends with an empty name, or at the first entry that
+/// doesn't fit in the module.
+#[derive(Debug, Clone)]
+pub struct KeywordTable<'a> {
+	bytes: &'a Slice32,
+	/// Offset of the module within the ROM image.
+	offset: u32,
+	/// Offset of the next entry within the module; `u32::MAX` once finished.
+	pos: u32,
+	endian: Endian,
+}
+
+impl<'a> KeywordTable<'a> {
+	fn word(&self, at: u32) -> Option<u32> {
+		self.bytes.read_word_endian(at, self.endian)
+	}
+
+	/// Returns the string at `rel` within the module, unless `rel` is 0 or out of range.
+	fn string(&self, rel: u32) -> Option<&'a Slice32> {
+		if rel == 0 {
+			return None;
+		}
+		self.bytes.subslice_from(rel)?.cstr()
+	}
+
+	fn read_entry(&self, pos: u32) -> Option<(KeywordEntry<'a>, u32)> {
+		let name = self.bytes.subslice_from(pos)?.cstr().filter(|n| !n.is_empty())?;
+		let fields = round_up_to_word(pos.checked_add(name.len() + 1)?)?;
+		let [code, flags, _syntax, help] = [0, 4, 8, 12].map(|n| self.word(fields + n));
+		let (code, flags, help) = (code?, flags?, help?);
+
+		let help = if flags & HELP_IS_CODE == 0 { self.string(help) } else { None };
+		let entry = match code {
+			0 => KeywordEntry::HelpText { name, help },
+			code => KeywordEntry::Command {
+				name, code_offset: self.offset.checked_add(code)?, flags, help,
+			},
+		};
+		Some((entry, fields + 16))
+	}
+}
+
+impl<'a> Iterator for KeywordTable<'a> {
+	type Item = KeywordEntry<'a>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let pos = std::mem::replace(&mut self.pos, u32::MAX);
+		let (entry, next) = self.read_entry(pos)?;
+		self.pos = next;
+		Some(entry)
+	}
+}
+
+impl<'a> FusedIterator for KeywordTable<'a> { }
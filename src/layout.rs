@@ -0,0 +1,224 @@
+use std::{borrow::Borrow, ops::Range};
+
+use crate::{Rom, Slice32, FOOTER_LEN};
+
+/// What a [`Region`] of the ROM image contains.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RegionKind {
+	/// Entry point and bootloader, up to and including the `MODULE#` kernel marker.
+	Bootloader,
+	/// The kernel, up to the start of the module chain.
+	Kernel,
+	/// A single module, including the chain length word that precedes it.
+	Module,
+	/// Content that starts before the end of the previous region: the chain overlaps itself,
+	/// runs backwards, starts before the kernel marker, or runs into the footer.
+	Overlap,
+	/// Space not claimed by anything else, such as fill after the last module.
+	Padding,
+	/// The trailing 12 bytes of the image.
+	Footer,
+	/// Content that couldn't be classified, because the kernel or module chain wasn't found.
+	Unknown,
+}
+
+/// A contiguous range of the ROM image, tagged by what it contains.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+	/// The kind of content in this region.
+	pub kind: RegionKind,
+	/// The byte range of this region within the ROM image.
+	pub range: Range<u32>,
+}
+
+/// One of the parts of a ROM image, as described in the [`Rom`] documentation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Section {
+	/// Entry point and bootloader; see [`Rom::bootloader`].
+	Bootloader,
+	/// The kernel; see [`Rom::kernel`].
+	Kernel,
+	/// The module chain, from the first chain entry to the end of the last module.
+	Modules,
+	/// The trailing 12 bytes of the image; see [`Rom::footer`].
+	Footer,
+}
+
+impl<M: Borrow<[u8]>> Rom<M> {
+	/// Returns the bytes making up `which` part of the image, or `None` if it couldn't be found.
+	pub fn section(&self, which: Section) -> Option<&Slice32> {
+		match which {
+			Section::Bootloader => self.bootloader(),
+			Section::Kernel => self.kernel(),
+			Section::Modules => {
+				let start = self.module_chain_start()?.get();
+				let end = self.module_chain().last()?.next_offset()?;
+				self.slice(start .. end)
+			},
+			Section::Footer => self.footer(),
+		}
+	}
+
+	/// Returns the ordered list of regions making up the ROM image.
+	///
+	/// Regions are built from [`kernel_start`](Self::kernel_start), the module chain and the
+	/// footer. They tile the image from start to end, except that [`RegionKind::Overlap`]
+	/// regions may cover bytes already claimed by an earlier region.
+	///
+	/// If the kernel marker is found after the start of the module chain, the bootloader runs
+	/// up to the marker, and any modules before it are reported as overlaps.
+	pub fn layout(&self) -> Vec<Region> {
+		let len = self.as_slice32().len();
+		let footer_start = if len >= FOOTER_LEN { len - FOOTER_LEN } else { len };
+
+		let mut regions = Vec::new();
+		let mut push = |kind, range: Range<u32>| {
+			if !range.is_empty() {
+				regions.push(Region { kind, range });
+			}
+		};
+
+		let kernel_start = self.kernel_start().map(|n| n.get());
+		let chain_start = self.module_chain_start().map(|n| n.get());
+		let mut cursor = match (kernel_start, chain_start) {
+			(Some(k), Some(c)) if k <= c => {
+				push(RegionKind::Bootloader, 0..k);
+				push(RegionKind::Kernel, k..c);
+				c
+			},
+			(Some(k), None) => {
+				push(RegionKind::Bootloader, 0..k);
+				push(RegionKind::Kernel, k..footer_start);
+				footer_start
+			},
+			(Some(k), Some(_)) => {
+				// out of order: the kernel has no end, and modules before `k` become overlaps
+				push(RegionKind::Bootloader, 0..k);
+				k
+			},
+			(None, Some(c)) => {
+				push(RegionKind::Unknown, 0..c);
+				c
+			},
+			(None, None) => {
+				push(RegionKind::Unknown, 0..footer_start);
+				footer_start
+			},
+		};
+
+		for module in self.module_chain() {
+			let entry = module.offset() - 4;
+			let Some(end) = module.next_offset() else { break };
+
+			if entry < cursor {
+				push(RegionKind::Overlap, entry..end);
+			} else {
+				push(RegionKind::Padding, cursor..entry);
+				push(RegionKind::Module, entry..end);
+			}
+			cursor = cursor.max(end);
+		}
+
+		push(RegionKind::Padding, cursor..footer_start);
+		push(RegionKind::Overlap, footer_start..cursor.min(len));
+		push(RegionKind::Footer, cursor.clamp(footer_start, len)..len);
+		regions
+	}
+
+	/// Returns the number of padding bytes in the image.
+	///
+	/// Only bytes inside [`RegionKind::Padding`] regions are considered. If a
+	/// [fill byte](Self::fill_byte) can be identified (some ROM builders pad with 0x00, others
+	/// with 0xff), only bytes of that value are counted; otherwise the padding regions are
+	/// counted in full.
+	pub fn padding_bytes(&self) -> u32 {
+		let fill = self.fill_byte();
+		self.layout().into_iter()
+			.filter(|r| r.kind == RegionKind::Padding)
+			.filter_map(|r| self.slice(r.range))
+			.map(|region| match fill {
+				Some(fill) => region.count_byte(fill),
+				None => region.len(),
+			})
+			.sum()
+	}
+
+	/// Returns the fraction of the image that is real content, i.e. not
+	/// [padding](Self::padding_bytes), between 0 and 1.
+	///
+	/// An empty image has a utilisation of 0.
+	pub fn utilisation(&self) -> f32 {
+		let len = self.as_slice32().len();
+		if len == 0 {
+			return 0.0;
+		}
+		(len - self.padding_bytes()) as f32 / len as f32
+	}
+
+	/// Splits the image into consecutive windows of `window` bytes, and returns the offset and
+	/// Shannon entropy (in bits per byte, from 0 to 8) of each.
+	///
+	/// Padding scores near 0 and ARM code typically around 5 to 6, while compressed or
+	/// encrypted data scores close to 8, so it stands out. The last window may be shorter than
+	/// the rest. Returns nothing if `window` is 0.
+	///
+	/// This reads every byte of the image once, and allocates one entry per window, so small
+	/// windows over a large image produce a lot of output; a few hundred bytes to a few KiB is
+	/// usually a good balance between resolution and noise.
+	pub fn entropy_windows(&self, window: u32) -> Vec<(u32, f32)> {
+		if window == 0 {
+			return Vec::new();
+		}
+
+		let len = self.as_slice32().len();
+		(0 .. len).step_by(window as usize)
+			.filter_map(|start| {
+				let bytes = self.slice(start .. start.saturating_add(window).min(len))?;
+				Some((start, entropy(bytes)))
+			})
+			.collect()
+	}
+
+	/// Returns the byte used to pad the image, typically 0x00 or 0xff, or `None` if there is no
+	/// clear padding.
+	///
+	/// This is a majority vote over the 4 KiB just before the 12-byte footer (or as much of the
+	/// image as there is): the fill byte must make up more than half of that window. The result
+	/// is suitable for passing to [`Slice32::trim_end_matches`].
+	pub fn fill_byte(&self) -> Option<u8> {
+		const WINDOW: u32 = 4 << 10;
+
+		let len = self.as_slice32().len();
+		let end = len.saturating_sub(FOOTER_LEN);
+		let window = self.slice(end.saturating_sub(WINDOW) .. end)?;
+		majority_byte(window)
+	}
+}
+
+/// Returns the number of times each byte value occurs in `bytes`.
+fn byte_counts(bytes: &Slice32) -> [u32; 256] {
+	let mut counts = [0u32; 256];
+	for &b in bytes.as_ref() {
+		counts[b as usize] += 1;
+	}
+	counts
+}
+
+/// Returns the Shannon entropy of `bytes`, in bits per byte.
+fn entropy(bytes: &Slice32) -> f32 {
+	let len = bytes.len() as f32;
+	byte_counts(bytes).into_iter().filter(|&n| n != 0)
+		.map(|n| {
+			let p = n as f32 / len;
+			-p * p.log2()
+		})
+		.sum()
+}
+
+/// Returns the byte making up more than half of `bytes`, if there is one.
+fn majority_byte(bytes: &Slice32) -> Option<u8> {
+	let counts = byte_counts(bytes);
+	let (byte, &count) = counts.iter().enumerate().max_by_key(|&(_, n)| *n)?;
+	(count > bytes.len() / 2).then_some(byte as u8)
+}
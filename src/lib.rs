@@ -4,19 +4,51 @@
 #![cfg_attr(debug_assertions, allow(dead_code))]
 
 mod heuristics;
-pub use heuristics::KnownRiscOsVersion;
+pub use heuristics::{Identification, KnownRiscOsVersion};
 
 mod bintrinsics;
-pub use bintrinsics::Slice32;
+pub use bintrinsics::{
+	CStrs, Endian, Slice32, decode_arm_branch, round_down_to_word, round_up_to_word,
+};
 use heuristics::RomHeuristics;
 
+mod layout;
+pub use layout::{Region, RegionKind, Section};
+
+mod builder;
+pub use builder::RomBuilder;
+
+mod export;
+
+mod strings;
+pub use strings::Strings;
+
+mod keywords;
+pub use keywords::{KeywordEntry, KeywordTable};
+
+mod checksum;
+pub use checksum::{ChecksumKind, ChecksumLayout, ChecksumStatus};
+
+#[cfg(feature = "sha")]
+mod digests;
+#[cfg(feature = "sha")]
+pub use digests::DatEntry;
+
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
 use std::{
-	cell::Cell,
+	cell::{Cell, OnceCell, RefCell},
+	collections::{BTreeSet, HashMap},
 	error::Error,
 	fmt,
-	io::{self, Read},
+	hash::{Hash, Hasher},
+	io::{self, Read, Seek, SeekFrom, Write},
 	num::NonZeroU32,
-	ops::Deref,
+	ops::{BitOr, Deref, Range},
 	path::Path,
 	iter::FusedIterator, borrow::Borrow,
 };
@@ -28,22 +60,68 @@ type CachedOffset = Cell<Option<Offset>>;
 
 /// Reasons why Roxtract will refuse to load a ROM image file.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum RomLoadError {
 	/// The underlying device failed on an I/O operation
 	Io(io::Error),
-	/// The ROM is an invalid size
-	RomInvalidSize,
+	/// The ROM is larger than the size limit
+	RomTooLarge {
+		/// The size of the ROM image, in bytes
+		actual: u64,
+		/// The largest permitted size, in bytes
+		limit: u32,
+	},
+	/// The ROM size is not a multiple of 4 bytes
+	RomMisaligned {
+		/// The size of the ROM image, in bytes
+		actual: u64,
+	},
+	/// The number of prefix bytes to skip exceeds the size of the data
+	SkipBeyondEnd {
+		/// The number of bytes to be skipped
+		skip: u64,
+		/// The size of the data, in bytes
+		actual: u64,
+	},
+	/// The requested range of bytes is not within the ROM image
+	RangeOutOfBounds {
+		/// The start of the requested range
+		start: u32,
+		/// The end of the requested range
+		end: u32,
+		/// The size of the ROM image, in bytes
+		actual: u64,
+	},
 }
 
 /// Reasons why Roxtract cannot understand a loaded ROM image.
 #[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum RomDecodeError {
 	/// UtilityModule, the start of the ROM module chain, was not found
 	UtilityModuleNotFound,
 	/// The module chain is broken, suggesting the ROM image is corrupted
-	ModuleChainBroken,
+	ModuleChainBroken {
+		/// The position of the chain entry (length word) where the walk failed
+		module_offset: u32,
+	},
 	/// A C-string was not terminated
-	UnterminatedCstr,
+	UnterminatedCstr {
+		/// The offset in the ROM image where the string should start
+		at: u32,
+	},
+	/// The first word of the image is not a branch to somewhere within the image
+	ResetNotBranch {
+		/// The first word of the image, or 0 if the image is empty
+		word: u32,
+	},
+	/// The `MODULE#` marker that precedes the kernel was not found
+	KernelMarkerNotFound,
+	/// The module chain continues beyond the limit given to [`Rom::module_chain_limited`]
+	ModuleLimitReached {
+		/// The position of the first chain entry beyond the limit
+		module_offset: u32,
+	},
 }
 
 impl From<io::Error> for RomLoadError {
@@ -66,8 +144,14 @@ impl fmt::Display for RomLoadError {
 		match self {
 			RomLoadError::Io(e)
 				=> write!(f, "I/O error: {}", e),
-			RomLoadError::RomInvalidSize
-				=> f.write_str("ROM invalid size (mut be 32-bit aligned and no more than 12 MiB)"),
+			RomLoadError::RomTooLarge { actual, limit }
+				=> write!(f, "ROM too large ({} bytes; limit is {} bytes)", actual, limit),
+			RomLoadError::RomMisaligned { actual }
+				=> write!(f, "ROM size ({} bytes) is not a multiple of 4", actual),
+			RomLoadError::SkipBeyondEnd { skip, actual }
+				=> write!(f, "Cannot skip {} bytes of {}-byte file", skip, actual),
+			RomLoadError::RangeOutOfBounds { start, end, actual }
+				=> write!(f, "Range {:#x}..{:#x} is not within {}-byte ROM", start, end, actual),
 		}
 	}
 }
@@ -77,10 +161,17 @@ impl fmt::Display for RomDecodeError {
 		match self {
 			RomDecodeError::UtilityModuleNotFound
 				=> f.write_str("Could not find UtilityModule in ROM (is file corrupted?)"),
-			RomDecodeError::ModuleChainBroken
-				=> f.write_str("Module chain appears to be broken"),
-			RomDecodeError::UnterminatedCstr
-				=> f.write_str("C-string terminator could not be located"),
+			RomDecodeError::ModuleChainBroken { module_offset }
+				=> write!(f, "Module chain broken at module offset {:#x}", module_offset),
+			RomDecodeError::UnterminatedCstr { at }
+				=> write!(f, "Unterminated C-string at {:#x}", at),
+			RomDecodeError::ResetNotBranch { word }
+				=> write!(f, "First word ({:#010x}) is not a branch within the ROM", word),
+			RomDecodeError::KernelMarkerNotFound
+				=> f.write_str("Could not find MODULE# kernel marker in ROM"),
+			RomDecodeError::ModuleLimitReached { module_offset }
+				=> write!(f, "Module chain exceeds module limit at module offset {:#x}",
+					module_offset),
 		}
 	}
 }
@@ -100,57 +191,183 @@ impl Error for RomDecodeError { }
 /// The ROM image has to be contiguous in system memory.
 pub struct Rom<M: Borrow<[u8]> = Box<[u8]>> {
 	data: M,
+	skip: u32,
+	config: RomConfig,
 
 	kernel_start: CachedOffset,
 	module_chain_start: CachedOffset,
 	version_name_str: CachedOffset,
+
+	find_cache: RefCell<HashMap<Box<[u8]>, Option<u32>>>,
+	chain_entries: OnceCell<Box<[u32]>>,
 }
 
 const ROM_LIMIT: u32 = 12 << 20; // 12 MiB limit in the Archimedes memory map
+const FOOTER_LEN: u32 = 12;
+const DEFAULT_LOGICAL_BASE: u32 = 0x0380_0000; // where Arthur and RISC OS 2/3 ROMs are mapped
+const SOFTLOAD_RAM: Range<u32> = 0x0200_0000..0x0300_0000; // physically mapped RAM under MEMC
+
+/// Interpretation settings carried by each `Rom`, as set by [`RomBuilder`].
+#[derive(Debug, Clone, Copy)]
+struct RomConfig {
+	logical_base: u32,
+	endian: Endian,
+	checksum_layout: Option<ChecksumLayout>,
+}
+
+impl Default for RomConfig {
+	fn default() -> Self {
+		RomConfig { logical_base: DEFAULT_LOGICAL_BASE, endian: Endian::Little,
+			checksum_layout: None }
+	}
+}
+
+/// Checks that `skip` bytes can be removed from `len` bytes of data, returning the remainder.
+fn check_skip(len: u64, skip: u64) -> Result<u64, RomLoadError> {
+	len.checked_sub(skip).ok_or(RomLoadError::SkipBeyondEnd { skip, actual: len })
+}
+
+/// Checks that a ROM image of `len` bytes is no larger than `limit` and word-aligned.
+fn check_rom_len(len: u64, limit: u32) -> Result<u32, RomLoadError> {
+	if len > limit as u64 {
+		return Err(RomLoadError::RomTooLarge { actual: len, limit });
+	}
+	if len & 3 != 0 {
+		return Err(RomLoadError::RomMisaligned { actual: len });
+	}
+	Ok(len as u32)
+}
 
 impl Rom<Box<[u8]>> {
+	/// Creates a `Rom` with no contents.
+	///
+	/// This is a valid `Rom`, in which nothing can be found: there's no kernel, no module chain
+	/// and no recognised version. It's mostly useful for testing error paths.
+	pub fn empty() -> Self {
+		Rom::wrap(Box::default(), 0, RomConfig::default())
+	}
+
 	/// Creates a `Rom` owning its contents from a file.
 	pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, RomLoadError> {
-		Self::from_file_impl(path.as_ref())
+		RomBuilder::new().open_file(path)
 	}
 
-	fn from_file_impl(path: &Path) -> Result<Self, RomLoadError> {
-		let mut file = std::fs::File::open(path)?;
+	/// Creates a `Rom` owning its contents from a file, where the ROM image starts `skip` bytes
+	/// into the file (e.g. after a container header).
+	///
+	/// The usual size and alignment checks apply to the image after the skipped prefix.
+	pub fn from_file_at<P: AsRef<Path>>(path: P, skip: u64) -> Result<Self, RomLoadError> {
+		Self::from_file_impl(path.as_ref(), skip, ROM_LIMIT, RomConfig::default())
+	}
 
-		let rom_len = match file.metadata()?.len() {
-			// small enough and word-aligned?
-			n if n <= ROM_LIMIT as u64 && n & 3 == 0 => n as u32,
-			_ => return Err(RomLoadError::RomInvalidSize),
-		};
+	/// Creates a `Rom` owning its contents by reading `reader` to the end.
+	///
+	/// At most one byte more than the size limit is read, so an oversized stream is rejected
+	/// without being buffered in full.
+	pub fn from_reader<R: Read>(reader: R) -> Result<Self, RomLoadError> {
+		let mut data = Vec::new();
+		reader.take(ROM_LIMIT as u64 + 1).read_to_end(&mut data)?;
+		check_rom_len(data.len() as u64, ROM_LIMIT)?;
+		Ok(Rom::wrap(data.into_boxed_slice(), 0, RomConfig::default()))
+	}
 
+	/// Creates a `Rom` owning its contents by reading exactly `expected_len` bytes from
+	/// `reader`, e.g. where a container or protocol reports the length up front.
+	///
+	/// The buffer is allocated once, up front. If the stream ends early, this fails with an
+	/// [`io::ErrorKind::UnexpectedEof`] error; any bytes after `expected_len` are left unread.
+	pub fn from_reader_sized<R: Read>(mut reader: R, expected_len: u32)
+	-> Result<Self, RomLoadError> {
+		let rom_len = check_rom_len(expected_len as u64, ROM_LIMIT)?;
 		let mut data = vec![0u8; rom_len as usize].into_boxed_slice();
-		file.read_exact(&mut data)?;
+		reader.read_exact(&mut data)?;
+		Ok(Rom::wrap(data, 0, RomConfig::default()))
+	}
 
-		Ok(Rom {
-			data,
+	/// Creates a `Rom` owning its contents from a file, also returning the CRC32 of the image.
+	///
+	/// The CRC is computed as each chunk of the file is read, rather than in a second pass over
+	/// the loaded image; it is identical to [`crc32`](Self::crc32).
+	pub fn from_file_crc<P: AsRef<Path>>(path: P) -> Result<(Self, u32), RomLoadError> {
+		let mut hasher = crc_any::CRCu32::crc32();
+		let rom = Self::from_file_with(path.as_ref(), 0, ROM_LIMIT, RomConfig::default(),
+			|chunk| hasher.digest(chunk))?;
+		Ok((rom, hasher.get_crc()))
+	}
 
-			kernel_start: CachedOffset::default(),
-			module_chain_start: CachedOffset::default(),
-			version_name_str: CachedOffset::default(),
-		})
+	fn from_file_impl(path: &Path, skip: u64, limit: u32, config: RomConfig)
+	-> Result<Self, RomLoadError> {
+		Self::from_file_with(path, skip, limit, config, |_| {})
+	}
+
+	/// Loads a file as for `from_file_impl`, passing each chunk to `on_chunk` as it's read.
+	fn from_file_with(path: &Path, skip: u64, limit: u32, config: RomConfig,
+		mut on_chunk: impl FnMut(&[u8])) -> Result<Self, RomLoadError> {
+		const CHUNK_LEN: usize = 64 << 10;
+
+		let mut file = std::fs::File::open(path)?;
+
+		let rom_len = check_rom_len(check_skip(file.metadata()?.len(), skip)?, limit)?;
+		file.seek(SeekFrom::Start(skip))?;
+
+		let mut data = vec![0u8; rom_len as usize].into_boxed_slice();
+		for chunk in data.chunks_mut(CHUNK_LEN) {
+			file.read_exact(chunk)?;
+			on_chunk(chunk);
+		}
+
+		Ok(Rom::wrap(data, 0, config))
 	}
 }
 
 impl<M: Borrow<[u8]>> Rom<M> {
 	/// Creates a `Rom` from some existing memory allocation containing a ROM image.
 	pub fn from_mem(mem: M) -> Result<Rom<M>, RomLoadError> {
-		let data = mem.borrow();
-		if data.len() > ROM_LIMIT as usize || data.len() & 3 != 0 {
-			return Err(RomLoadError::RomInvalidSize);
-		}
+		Self::from_mem_at(mem, 0)
+	}
+
+	/// Creates a `Rom` from some existing memory allocation containing a ROM image, without
+	/// checking its size or alignment.
+	///
+	/// This is for re-wrapping memory that has already been validated, such as the bytes of
+	/// another `Rom`. The ROM has the default configuration, as with [`from_mem`](Self::from_mem).
+	///
+	/// # Safety
+	///
+	/// - `mem` must be no longer than `i32::MAX` bytes, as for [`Slice32::new_unchecked`].
+	/// - `mem` must be a multiple of 4 bytes long.
+	pub unsafe fn from_mem_unchecked(mem: M) -> Rom<M> {
+		debug_assert!(check_rom_len(mem.borrow().len() as u64, i32::MAX as u32).is_ok());
+		Rom::wrap(mem, 0, RomConfig::default())
+	}
 
-		Ok(Rom {
-			data: mem,
+	/// Creates a `Rom` from some existing memory allocation, where the ROM image starts `skip`
+	/// bytes in (e.g. after a container header).
+	///
+	/// The usual size and alignment checks apply to the image after the skipped prefix.
+	pub fn from_mem_at(mem: M, skip: u32) -> Result<Rom<M>, RomLoadError> {
+		Self::from_mem_impl(mem, skip, ROM_LIMIT, RomConfig::default())
+	}
+
+	fn from_mem_impl(mem: M, skip: u32, limit: u32, config: RomConfig)
+	-> Result<Rom<M>, RomLoadError> {
+		check_rom_len(check_skip(mem.borrow().len() as u64, skip as u64)?, limit)?;
+		Ok(Rom::wrap(mem, skip, config))
+	}
+
+	fn wrap(data: M, skip: u32, config: RomConfig) -> Rom<M> {
+		Rom {
+			data,
+			skip,
+			config,
 
 			kernel_start: CachedOffset::default(),
 			module_chain_start: CachedOffset::default(),
 			version_name_str: CachedOffset::default(),
-		})
+
+			find_cache: RefCell::default(),
+			chain_entries: OnceCell::new(),
+		}
 	}
 }
 
@@ -159,10 +376,61 @@ impl<M: Borrow<[u8]>> Rom<M> {
 	#[inline]
 	pub fn as_slice32(&self) -> &Slice32 {
 		unsafe {
-			// SAFETY: we only allow construction of Roms <= 12 MiB
-			// so Slice32 will hold them no problem
-			Slice32::new_unchecked(self.data.borrow())
+			// SAFETY: we only allow construction of Roms within the size limit (after skipping
+			// the prefix), which is capped at i32::MAX, so Slice32 will hold them no problem
+			Slice32::new_unchecked(&self.data.borrow()[self.skip as usize ..])
+		}
+	}
+
+	/// Returns an iterator over each word-aligned word in the image, as `(offset, word)` pairs,
+	/// read in the ROM's byte order.
+	pub fn words(&self) -> impl ExactSizeIterator<Item = (u32, u32)> + FusedIterator + '_ {
+		let bytes = self.as_slice32();
+		(0 .. bytes.len() / 4).map(move |n| {
+			let offset = n * 4;
+			(offset, bytes.read_word_endian(offset, self.config.endian).unwrap())
+		})
+	}
+
+	/// Returns the offset of every aligned word in the image that points at `target_offset`.
+	///
+	/// With [`PointerMode::Address`], words are interpreted as logical addresses, via
+	/// [`address_to_offset`](Self::address_to_offset); with [`PointerMode::Offset`], they must
+	/// equal `target_offset` itself.
+	pub fn find_pointers_to(&self, target_offset: u32, mode: PointerMode) -> Vec<u32> {
+		let wanted = match mode {
+			PointerMode::Offset => Some(target_offset),
+			PointerMode::Address => self.offset_to_address(target_offset),
+		};
+		let Some(wanted) = wanted else { return Vec::new() };
+
+		self.words().filter(|&(_, word)| word == wanted).map(|(offset, _)| offset).collect()
+	}
+
+	/// Guesses whether the image was built to be soft-loaded into RAM, rather than run from ROM.
+	///
+	/// Each aligned word in the [bootloader](Self::bootloader) is classed as a ROM pointer if it
+	/// lies within the image when mapped at the [logical base](Self::logical_base), or a RAM
+	/// pointer if it lies in `0x02000000..0x03000000`, where MEMC machines map physical RAM. The
+	/// image is reported as soft-loaded if RAM pointers outnumber ROM pointers. Images without
+	/// a kernel marker are never reported as soft-loaded.
+	pub fn is_softload(&self) -> bool {
+		let Some(kernel_start) = self.kernel_start() else { return false };
+		let (mut rom, mut ram) = (0u32, 0u32);
+		for (_, word) in self.words().take_while(|&(offset, _)| offset < kernel_start.get()) {
+			if self.address_to_offset(word).is_some() {
+				rom += 1;
+			} else if SOFTLOAD_RAM.contains(&word) {
+				ram += 1;
+			}
 		}
+		ram > rom
+	}
+
+	/// Returns the bytes of the ROM image in `range`, or `None` if it isn't within the image.
+	#[inline]
+	pub fn slice(&self, range: Range<u32>) -> Option<&Slice32> {
+		self.as_slice32().subslice(range)
 	}
 
 	fn recell_offset<F: FnOnce() -> Option<u32>>(&self, cell: &CachedOffset, find: F)
@@ -176,7 +444,47 @@ impl<M: Borrow<[u8]>> Rom<M> {
 		result
 	}
 
+	/// Returns the logical address at which the ROM image is mapped.
+	///
+	/// Unless set with [`RomBuilder::logical_base`], this is `0x03800000`, as used by Arthur and
+	/// RISC OS 2 and 3.
+	#[inline]
+	pub fn logical_base(&self) -> u32 { self.config.logical_base }
+
+	/// Returns the byte order used to read words from the ROM image.
+	#[inline]
+	pub fn endianness(&self) -> Endian { self.config.endian }
+
+	/// Converts an offset within the ROM image to a logical address.
+	///
+	/// Returns `None` if `offset` is outside the image.
+	pub fn offset_to_address(&self, offset: u32) -> Option<u32> {
+		if offset >= self.as_slice32().len() {
+			return None;
+		}
+		self.config.logical_base.checked_add(offset)
+	}
+
+	/// Converts a logical address to an offset within the ROM image.
+	///
+	/// Returns `None` if `address` is outside the image.
+	pub fn address_to_offset(&self, address: u32) -> Option<u32> {
+		address.checked_sub(self.config.logical_base).filter(|n| *n < self.as_slice32().len())
+	}
+
+	/// Returns the offset of the `MODULE#\0` marker that precedes the kernel, or `None` if it
+	/// wasn't found.
+	///
+	/// This is always 8 bytes before [`kernel_start`](Self::kernel_start).
+	pub fn kernel_marker_offset(&self) -> Option<u32> {
+		self.kernel_start().map(|k| k.get() - 8)
+	}
+
 	/// Returns the offset of the kernel in the ROM image, or `None` if it wasn't found.
+	///
+	/// The kernel starts immediately after the first 8-byte `MODULE#\0` marker (see
+	/// [`kernel_marker_offset`](Self::kernel_marker_offset)), so this is the marker offset plus
+	/// 8. A marker right at the end of the image, with no kernel after it, is not accepted.
 	pub fn kernel_start(&self) -> Option<Offset> {
 		self.recell_offset(&self.kernel_start,
 			|| self.as_slice32().find(Slice32::new(b"MODULE#\0").unwrap())
@@ -184,33 +492,454 @@ impl<M: Borrow<[u8]>> Rom<M> {
 				))
 	}
 
+	/// Returns the offset of every `MODULE#` kernel marker in the image, in order.
+	///
+	/// A plain ROM image has exactly one; more than one suggests a dump of several concatenated
+	/// images, or a multi-image flash layout. Note that these are offsets to the markers
+	/// themselves, whereas [`kernel_start`](Self::kernel_start) points just after the first one.
+	pub fn find_all_kernels(&self) -> Vec<u32> {
+		self.as_slice32().find_all(Slice32::new(b"MODULE#\0").unwrap())
+	}
+
 	/// Returns the offset of the entry into the module chain, or `None` if `UtilityModule` wasn't
 	/// found.
+	///
+	/// Every copy of the `UtilityModule` title in the image is considered, along with every
+	/// header whose title offset points at it, until one passes
+	/// [`is_module_chain_start`](Self::is_module_chain_start). This way, a mention of the name
+	/// elsewhere in the image isn't mistaken for the module.
 	pub fn module_chain_start(&self) -> Option<Offset> {
-		self.recell_offset(&self.module_chain_start, ||
-			self.as_slice32().find_offset_to(Slice32::new(b"UtilityModule\0").unwrap(), 0x10)
-			.and_then(|n| n.checked_sub(4))
-		)
+		self.recell_offset(&self.module_chain_start, || {
+			let bytes = self.as_slice32();
+			bytes.find_all(Slice32::new(b"UtilityModule\0").unwrap()).into_iter()
+				.flat_map(|title| bytes.offsets_to(title, 0x10, self.config.endian))
+				.filter_map(|header| header.checked_sub(4))
+				.find(|&entry| self.is_module_chain_start(entry))
+		})
+	}
+
+	/// Returns `true` if `offset` is plausibly the chain entry (length word) of `UtilityModule`,
+	/// at the head of the module chain.
+	///
+	/// The length word must describe a module within the image, whose title is `UtilityModule`,
+	/// and the chain entry that follows it must also be within the image.
+	pub fn is_module_chain_start(&self, offset: u32) -> bool {
+		let Some(module) = self.module_chain_from(offset).next() else { return false };
+		module.title().is_ok_and(|t| t.as_ref() == b"UtilityModule")
+			&& module.next_offset().and_then(|n| n.checked_add(4))
+				.is_some_and(|end| end <= self.as_slice32().len())
+	}
+
+	/// Decodes the first word of the ROM image, which is executed on reset.
+	///
+	/// If that word is an ARM `B` or `BL` instruction, its target is decoded: the low 24 bits
+	/// are a signed word offset, which is shifted left by 2 and added to the address of the
+	/// instruction plus 8 (as the PC reads two instructions ahead). Otherwise, the raw word is
+	/// returned for the caller to interpret.
+	///
+	/// Returns `None` if the image is shorter than one word.
+	pub fn entry_point(&self) -> Option<EntryPoint> {
+		let word = self.as_slice32().read_word_endian(0, self.config.endian)?;
+		Some(match decode_arm_branch(word, 0) {
+			Some(target) => EntryPoint::Branch(target),
+			None => EntryPoint::Raw(word),
+		})
+	}
+
+	/// Returns the offset of the kernel's internal version name, such as
+	/// `RISC OS\t\t3.11 (29 Sep 1992)`.
+	///
+	/// The [kernel](Self::kernel) is searched for `RISC OS`, followed by at least one space or
+	/// tab, then a digit. The result is cached.
+	pub fn version_name_offset(&self) -> Option<u32> {
+		self.recell_offset(&self.version_name_str, || {
+			let kernel = self.kernel()?;
+			let found = kernel.find_all(Slice32::new(b"RISC OS").unwrap()).into_iter().find(|&at| {
+				let rest = kernel.subslice_from(at + 7).unwrap().as_ref();
+				let version_at = rest.iter().position(|&b| b != b' ' && b != b'\t');
+				matches!(version_at, Some(n) if n > 0 && rest[n].is_ascii_digit())
+			})?;
+			Some(self.kernel_start()?.get() + found)
+		}).map(NonZeroU32::get)
+	}
+
+	/// Returns the kernel's internal version name, without its terminator.
+	///
+	/// See [`version_name_offset`](Self::version_name_offset).
+	pub fn version_string(&self) -> Option<&Slice32> {
+		self.cstr_at(self.version_name_offset()?).ok()
+	}
+
+	/// Returns the entry point and bootloader, from the start of the image up to the kernel.
+	///
+	/// This includes the `MODULE#` marker that precedes the kernel.
+	pub fn bootloader(&self) -> Option<&Slice32> {
+		self.slice(0 .. self.kernel_start()?.get())
+	}
+
+	/// Returns the offset at which the kernel ends, and the module chain begins.
+	///
+	/// This is the same as [`module_chain_start`](Self::module_chain_start): the position of the
+	/// first module's length word.
+	pub fn kernel_end(&self) -> Option<u32> {
+		self.module_chain_start().map(NonZeroU32::get)
+	}
+
+	/// Returns the kernel, from [`kernel_start`](Self::kernel_start) up to
+	/// [`kernel_end`](Self::kernel_end).
+	pub fn kernel(&self) -> Option<&Slice32> {
+		self.slice(self.kernel_start()?.get() .. self.kernel_end()?)
+	}
+
+	/// Returns the trailing 12 bytes of the image, whose content is not yet understood.
+	///
+	/// Returns `None` if the image is shorter than 12 bytes.
+	pub fn footer(&self) -> Option<&Slice32> {
+		let len = self.as_slice32().len();
+		self.as_slice32().subslice_from(len.checked_sub(FOOTER_LEN)?)
+	}
+
+	/// Computes the CRC32 of the whole ROM image.
+	pub fn crc32(&self) -> u32 {
+		self.as_slice32().crc32()
+	}
+
+	/// Computes the CRC32 of the whole ROM image, calling `progress` with the number of bytes
+	/// hashed so far and the total: once before starting, then after each 64 KiB chunk.
+	///
+	/// The final call always reports the full length.
+	pub fn crc32_with_progress(&self, mut progress: impl FnMut(u32, u32)) -> u32 {
+		const CHUNK_LEN: usize = 64 << 10;
+
+		let bytes = self.as_slice();
+		let total = self.as_slice32().len();
+		let mut hasher = crc_any::CRCu32::crc32();
+		let mut done = 0u32;
+		progress(0, total);
+		for chunk in bytes.chunks(CHUNK_LEN) {
+			hasher.digest(chunk);
+			done += chunk.len() as u32;
+			progress(done, total);
+		}
+		hasher.get_crc()
+	}
+
+	/// Computes the CRC32 of the bytes in `range`, such as a region from [`layout`](Self::layout).
+	///
+	/// Returns `None` if `range` is not within the ROM image.
+	pub fn crc32_range(&self, range: Range<u32>) -> Option<u32> {
+		self.slice(range).map(Slice32::crc32)
+	}
+
+	/// Reads the C-string starting at `offset` in the ROM image, without its terminator.
+	///
+	/// Returns [`RomDecodeError::UnterminatedCstr`] if `offset` is out of range, or no
+	/// terminator follows it.
+	pub fn cstr_at(&self, offset: u32) -> Result<&Slice32, RomDecodeError> {
+		self.as_slice32().subslice_from(offset)
+			.and_then(Slice32::cstr)
+			.ok_or(RomDecodeError::UnterminatedCstr { at: offset })
+	}
+
+	/// Compares the ROM image against the known RISC OS versions.
+	///
+	/// See [`KnownRiscOsVersion::identify`].
+	pub fn identify(&self) -> Identification {
+		KnownRiscOsVersion::identify(self.as_slice())
+	}
+
+	/// Searches the ROM image for `needle`, returning the byte offset of its first occurrence.
+	///
+	/// Results (including failures) are remembered per needle, so repeated searches for the same
+	/// marker only scan the image once. Each distinct needle costs a copy of its bytes plus an
+	/// `Option<u32>` in a map owned by `self`; the cache is never pruned, so this is intended for
+	/// a modest, fixed set of markers rather than arbitrary user input.
+	pub fn find_cached(&self, needle: &[u8]) -> Option<u32> {
+		if let Some(cached) = self.find_cache.borrow().get(needle) {
+			return *cached;
+		}
+
+		let result = Slice32::new(needle).and_then(|n| self.as_slice32().find(n));
+		self.find_cache.borrow_mut().insert(needle.into(), result);
+		result
+	}
+
+	/// Returns `true` if the image looks like a RISC OS ROM, i.e. both the `MODULE#` kernel
+	/// marker and `UtilityModule` at the head of the module chain can be found, and the latter
+	/// [has a valid header](Module::has_valid_header).
+	///
+	/// Only the first module in the chain is read, and both lookups are cached, so this is cheap
+	/// enough to screen out non-ROM files before further analysis.
+	pub fn looks_valid(&self) -> bool {
+		self.kernel_start().is_some()
+			&& self.module_chain().next().is_some_and(|m| m.has_valid_header())
+	}
+
+	/// Checks the conventional structure at the start of the image, before anything relies on
+	/// it: the first word must be a branch to somewhere within the image (see
+	/// [`entry_point`](Self::entry_point)), and the `MODULE#` kernel marker and `UtilityModule`
+	/// must both be present.
+	///
+	/// This is stricter than [`looks_valid`](Self::looks_valid), and says what's wrong.
+	pub fn validate_header(&self) -> Result<(), RomDecodeError> {
+		let len = self.as_slice32().len();
+		if !matches!(self.entry_point(), Some(EntryPoint::Branch(target)) if target < len) {
+			let word = self.as_slice32().read_word_endian(0, self.config.endian).unwrap_or(0);
+			return Err(RomDecodeError::ResetNotBranch { word });
+		}
+		self.kernel_start().ok_or(RomDecodeError::KernelMarkerNotFound)?;
+		self.module_chain_start().ok_or(RomDecodeError::UtilityModuleNotFound)?;
+		Ok(())
 	}
 
 	/// Returns an iterator over all modules in the ROM chain.
+	///
+	/// The chain is walked in full the first time this is called, and the position of each
+	/// entry is remembered, so later iterators (and [`Iterator::nth`] on them) go straight to
+	/// each module instead of following the length words again.
 	pub fn module_chain(&self) -> ModuleChain<'_> {
-		ModuleChain::new(self, self.module_chain_start())
+		let entries = self.chain_entries.get_or_init(|| {
+			let start = self.module_chain_start().map_or(u32::MAX, NonZeroU32::get);
+			let mut chain = ModuleChain::new(self, start);
+			let mut entries = Vec::new();
+			// record every entry visited, including the terminator or break that ends the chain
+			while chain.pos != u32::MAX {
+				entries.push(chain.pos);
+				if !matches!(chain.next_entry(), Some(Ok(_))) {
+					break;
+				}
+			}
+			entries.into_boxed_slice()
+		});
+
+		ModuleChain { cached: Some(entries.iter()), ..ModuleChain::new(self, u32::MAX) }
+	}
+
+	/// Returns an iterator over all modules in the ROM chain, each paired with the chain length
+	/// word that precedes it.
+	///
+	/// The length word is the same as [`Module::chain_length`]; this is a convenience for tools
+	/// that display the chain as a sequence of entries.
+	pub fn module_chain_entries(&self) -> impl FusedIterator<Item = (u32, Module<'_>)> + '_ {
+		self.module_chain().map(|m| (m.chain_length(), m))
+	}
+
+	/// Returns the twelve header words at the start of `module` (0x00 to 0x2c inclusive),
+	/// exactly as stored, in the ROM's byte order. The flags word at 0x30, where present, is
+	/// not included.
+	///
+	/// Words beyond the end of the module are `None`. Nothing is decoded or checked, not even
+	/// the inferred [header length](Module::header_len), so this shows what the header accessors
+	/// have to work with.
+	pub fn module_header_raw(&self, module: &Module) -> [Option<u32>; 12] {
+		std::array::from_fn(|n| module.word(n as u32 * 4))
+	}
+
+	/// Returns the combined length of every module in the chain, including chain length words.
+	///
+	/// Only modules that [`module_chain`](Self::module_chain) walks successfully are counted, so
+	/// a broken chain contributes just the modules before the break.
+	pub fn total_module_bytes(&self) -> u32 {
+		self.module_chain().map(|m| m.chain_length()).sum()
+	}
+
+	/// Cross-checks each module's chain length word against where the next module actually is.
+	///
+	/// For each module in the chain, the following words are scanned for the next chain entry
+	/// holding a module with a [valid header](Module::has_valid_header), or the chain
+	/// terminator. Returns `(position, declared_length, actual_distance)` for each module whose
+	/// length word disagrees with that, where `position` is the offset of the length word.
+	/// `actual_distance` is 0 if nothing plausible follows the module at all.
+	///
+	/// On a clean ROM, this is empty. Being heuristic, this can be misled by module contents
+	/// that happen to look like a module header.
+	pub fn check_length_words(&self) -> Vec<(u32, u32, u32)> {
+		let endian = self.config.endian;
+		let len = self.as_slice32().len();
+		let is_next = |at: u32, declared_end: u32| {
+			(at == declared_end && self.as_slice32().read_word_endian(at, endian) == Some(0))
+				|| self.module_chain_from(at).next().is_some_and(|m| m.has_valid_header())
+		};
+
+		self.module_chain().filter_map(|module| {
+			let entry = module.offset() - 4;
+			let declared = module.chain_length();
+			let declared_end = entry + declared;
+			let actual = (entry + 8 .. len).step_by(4)
+				.find(|&at| is_next(at, declared_end))
+				.map_or(0, |at| at - entry);
+			(actual != declared).then_some((entry, declared, actual))
+		}).collect()
+	}
+
+	/// Returns an iterator over the modules in the ROM chain for which `pred` returns `true`.
+	///
+	/// ```no_run
+	/// # let rom = roxtract::Rom::from_file("riscos.rom").unwrap();
+	/// // modules that provide SWIs
+	/// let swi_providers = rom.modules_where(|m| m.swi_chunk_base().is_some());
+	/// // modules that can run on a 32-bit kernel
+	/// let clean = rom.modules_where(|m| m.is_32bit_compatible() == Some(true));
+	/// # drop((swi_providers, clean));
+	/// ```
+	pub fn modules_where<'a, F: Fn(&Module) -> bool + 'a>(&'a self, pred: F)
+	-> impl FusedIterator<Item = Module<'a>> + 'a {
+		self.module_chain().filter(move |m| pred(m))
+	}
+
+	/// Returns an iterator over the modules in the ROM chain whose titles start with `prefix`,
+	/// such as `b"Net"` for the networking modules.
+	///
+	/// The comparison is byte-for-byte, so is case-sensitive. Modules whose titles can't be read
+	/// are skipped.
+	pub fn modules_with_prefix<'a>(&'a self, prefix: &'a [u8])
+	-> impl FusedIterator<Item = Module<'a>> + 'a {
+		self.modules_where(move |m| m.title().is_ok_and(|t| t.as_ref().starts_with(prefix)))
+	}
+
+	/// Returns the module whose header starts exactly at `offset`, as given by
+	/// [`Module::offset`], reading its length from the chain entry just before it.
+	///
+	/// This doesn't walk the chain, so `offset` needn't be in it. Returns `None` if there's no
+	/// room for a module there, or if it doesn't [have a valid header](Module::has_valid_header).
+	pub fn module_at_offset(&self, offset: u32) -> Option<Module<'_>> {
+		self.module_chain_from(offset.checked_sub(4)?).next().filter(Module::has_valid_header)
+	}
+
+	/// Returns an iterator over the modules in the ROM chain whose titles contain `needle`,
+	/// such as `b"FS"` for filing systems.
+	///
+	/// The comparison is byte-for-byte, so is case-sensitive; an empty `needle` matches every
+	/// title. Modules whose titles can't be read are skipped. Although the chain itself is only
+	/// walked once per `Rom` (see [`module_chain`](Self::module_chain)), every title is searched
+	/// again on each call.
+	pub fn modules_containing_title<'a>(&'a self, needle: &'a [u8])
+	-> impl FusedIterator<Item = Module<'a>> + 'a {
+		self.modules_where(move |m| m.title().is_ok_and(|t| needle.is_empty()
+			|| Slice32::new(needle).is_some_and(|n| t.find(n).is_some())))
+	}
+
+	/// Returns the module whose SWI chunk starts at `base`, as given by
+	/// [`Module::swi_chunk_base`].
+	///
+	/// Returns `None` if no module in the chain provides that chunk.
+	pub fn module_by_swi_base(&self, base: u32) -> Option<Module<'_>> {
+		self.module_chain().find(|m| m.swi_chunk_base() == Some(base))
+	}
+
+	/// Returns an iterator over the title of each module in the ROM chain.
+	///
+	/// A title that can't be decoded yields an error for that module only; iteration continues
+	/// with the next module.
+	pub fn module_names(&self)
+	-> impl FusedIterator<Item = Result<&Slice32, RomDecodeError>> + '_ {
+		self.module_chain().map(|m| m.title())
+	}
+
+	/// Returns the title of each module in the ROM chain, decoded as Latin-1.
+	///
+	/// A title that is empty or can't be decoded is replaced with a placeholder of the form
+	/// `<unnamed@0x…>`, giving the module offset. This never fails, however corrupt the ROM.
+	pub fn module_names_lossy(&self) -> Vec<String> {
+		self.module_chain().map(|m| m.lossy_title()).collect()
+	}
+
+	/// Returns the set of module titles in the ROM chain, for comparison with other ROMs.
+	///
+	/// Titles that can't be decoded are left out.
+	pub fn module_name_set(&self) -> BTreeSet<Box<[u8]>> {
+		self.module_names().filter_map(|n| Some(n.ok()?.as_ref().into())).collect()
+	}
+
+	/// Returns an iterator over all modules in the ROM chain, which reports failures.
+	///
+	/// If the chain can't be followed to its terminating zero word, the final item is
+	/// [`RomDecodeError::ModuleChainBroken`]; if the chain can't be found at all, the only
+	/// item is [`RomDecodeError::UtilityModuleNotFound`].
+	pub fn try_module_chain(&self) -> TryModuleChain<'_> {
+		TryModuleChain {
+			chain: self.module_chain(),
+			not_found: self.module_chain_start().is_none(),
+			remaining: usize::MAX,
+		}
+	}
+
+	/// Returns an iterator over at most `max` modules in the ROM chain, which reports failures.
+	///
+	/// This behaves like [`try_module_chain`](Self::try_module_chain), except that if the chain
+	/// continues after `max` modules, the final item is
+	/// [`RomDecodeError::ModuleLimitReached`]. This gives tools handling untrusted images a hard
+	/// ceiling on the work done per image, regardless of its size.
+	///
+	/// (Each chain entry is at least 4 bytes long, so [`module_chain`](Self::module_chain) is
+	/// already bounded by the image length, and needs no limit of its own.)
+	pub fn module_chain_limited(&self, max: usize) -> TryModuleChain<'_> {
+		TryModuleChain { remaining: max, ..self.try_module_chain() }
+	}
+
+	/// Returns an iterator over the module chain, starting from the chain entry at `offset`.
+	///
+	/// Like [`module_chain_start`](Self::module_chain_start), `offset` is the position of a
+	/// module's length word, i.e. 4 bytes before [`Module::offset`]. If `offset` is not
+	/// word-aligned or lies outside the ROM image, the returned chain is empty.
+	pub fn module_chain_from(&self, offset: u32) -> ModuleChain<'_> {
+		let valid = offset & 3 == 0 && offset < self.as_slice32().len();
+		ModuleChain::new(self, if valid { offset } else { u32::MAX })
+	}
+
+	/// Splits the image into consecutive banks of `bank_size` bytes, each treated as an
+	/// independent ROM image (with its own kernel and module chain).
+	///
+	/// Each bank shares the configuration of `self`, such as its logical base address. Returns
+	/// `None` unless `bank_size` is a non-zero multiple of 4 that divides the image length.
+	pub fn banks(&self, bank_size: u32)
+	-> Option<impl ExactSizeIterator<Item = Rom<&Slice32>> + '_> {
+		let len = self.as_slice32().len();
+		if bank_size == 0 || bank_size & 3 != 0 || !len.is_multiple_of(bank_size) {
+			return None;
+		}
+
+		Some((0 .. len / bank_size).map(move |n| {
+			let start = n * bank_size;
+			let bank = self.slice(start .. start + bank_size).unwrap();
+			Rom::wrap(bank, 0, self.config)
+		}))
+	}
+
+	/// Copies the bytes in `range` into a new `Rom`, such as to treat a ROM image embedded in a
+	/// larger dump as a ROM in its own right.
+	///
+	/// The new `Rom` shares the configuration of `self`, such as its logical base address, and
+	/// the usual size and alignment checks apply to it.
+	pub fn clone_region(&self, range: Range<u32>) -> Result<Rom, RomLoadError> {
+		let actual = self.as_slice32().len();
+		let Some(bytes) = self.slice(range.clone()) else {
+			return Err(RomLoadError::RangeOutOfBounds {
+				start: range.start, end: range.end, actual: actual as u64,
+			});
+		};
+		Rom::from_mem_impl(Box::from(bytes.as_ref()), 0, ROM_LIMIT, self.config)
 	}
 
 	/// Returns a `Rom` object that transparently borrows the data of `self` as a `Slice32`.
-	pub fn as_ref<'a>(&'a self) -> Rom<&'a Slice32> {
+	///
+	/// The search and module chain caches are not carried over; the borrowed view starts empty.
+	pub fn as_ref(&self) -> Rom<&Slice32> {
 		Rom {
 			data: self.as_slice32(),
+			skip: 0,
+			config: self.config,
 			kernel_start: self.kernel_start.clone(),
 			module_chain_start: self.module_chain_start.clone(),
 			version_name_str: self.version_name_str.clone(),
+			find_cache: RefCell::default(),
+			chain_entries: OnceCell::new(),
 		}
 	}
 
 	/// Returns a raw slice to the ROM image data.
 	pub fn as_slice(&self) -> &[u8] {
-		self.data.borrow().as_ref()
+		self.as_slice32().as_ref()
 	}
 }
 
@@ -225,7 +954,7 @@ impl Deref for Rom {
 impl<M: Borrow<[u8]>> Borrow<[u8]> for Rom<M> {
 	#[inline]
 	fn borrow(&self) -> &[u8] {
-		self.data.borrow()
+		self.as_slice()
 	}
 }
 
@@ -236,21 +965,171 @@ impl<M: Borrow<[u8]>> Borrow<Slice32> for Rom<M> {
 	}
 }
 
+/// ROMs compare equal if their image bytes are equal.
+///
+/// Only the bytes are considered: configuration such as the logical base address and byte
+/// order, any skipped prefix, and the state of cached lookups are all ignored.
+impl<M: Borrow<[u8]>, N: Borrow<[u8]>> PartialEq<Rom<N>> for Rom<M> {
+	fn eq(&self, other: &Rom<N>) -> bool {
+		self.as_slice() == other.as_slice()
+	}
+}
+
+impl<M: Borrow<[u8]>> Eq for Rom<M> { }
+
+/// Hashes the image bytes only, consistent with the [`PartialEq`] implementation.
+impl<M: Borrow<[u8]>> Hash for Rom<M> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.as_slice().hash(state);
+	}
+}
+
+impl<'a> TryFrom<&'a [u8]> for Rom<&'a Slice32> {
+	type Error = RomLoadError;
+
+	/// Borrows a ROM image; equivalent to [`Rom::from_mem`].
+	fn try_from(mem: &'a [u8]) -> Result<Self, Self::Error> {
+		let mem = Slice32::new(mem)
+			.ok_or(RomLoadError::RomTooLarge { actual: mem.len() as u64, limit: ROM_LIMIT })?;
+		Rom::from_mem(mem)
+	}
+}
+
+impl TryFrom<Box<[u8]>> for Rom {
+	type Error = RomLoadError;
+
+	/// Takes ownership of a ROM image; equivalent to [`Rom::from_mem`].
+	fn try_from(mem: Box<[u8]>) -> Result<Self, Self::Error> {
+		Rom::from_mem(mem)
+	}
+}
+
+/// The instruction at the start of a ROM image, as returned by [`Rom::entry_point`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryPoint {
+	/// A branch to the given offset within the ROM image.
+	Branch(u32),
+	/// Some other instruction, given as its raw word.
+	Raw(u32),
+}
+
+/// How [`Rom::find_pointers_to`] interprets the words it scans.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointerMode {
+	/// Words are offsets from the start of the ROM image.
+	Offset,
+	/// Words are logical addresses, relative to the ROM's
+	/// [logical base](Rom::logical_base).
+	Address,
+}
+
+/// A set of module header fields, to be resolved as each module is read from the chain.
+///
+/// Used with [`ModuleChain::with_fields`]. Fields combine with `|`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ModuleFields(u8);
+
+impl ModuleFields {
+	/// No fields; each is resolved on first use instead.
+	pub const NONE: Self = Self(0);
+	/// The [title](Module::title).
+	pub const TITLE: Self = Self(1 << 0);
+	/// The inferred [header length](Module::header_len), which the accessors for later header
+	/// fields depend on.
+	pub const HEADER_LEN: Self = Self(1 << 1);
+	/// Every field that can be resolved in advance.
+	pub const ALL: Self = Self(Self::TITLE.0 | Self::HEADER_LEN.0);
+
+	/// Returns `true` if every field in `other` is also in `self`.
+	pub const fn contains(self, other: Self) -> bool {
+		self.0 & other.0 == other.0
+	}
+}
+
+impl BitOr for ModuleFields {
+	type Output = Self;
+
+	fn bitor(self, rhs: Self) -> Self {
+		Self(self.0 | rhs.0)
+	}
+}
+
 /// An iterator over each module in the ROM image.
+///
+/// Cloning a chain is cheap, and the clone continues from the same point, so it can be used
+/// to look ahead without disturbing the original.
+#[derive(Clone)]
 pub struct ModuleChain<'a> {
 	rom: &'a Slice32,
 	pos: u32,
+	endian: Endian,
+	/// Entry positions from an earlier walk, followed instead of `pos` if present.
+	cached: Option<std::slice::Iter<'a, u32>>,
+	/// Header fields to resolve on each module before it's yielded.
+	fields: ModuleFields,
 }
 
 impl<'a> ModuleChain<'a> {
-	fn new<M: Borrow<[u8]>>(rom: &'a Rom<M>, start: Option<Offset>) -> Self {
-		ModuleChain { rom: rom.as_slice32(), pos: start.map(NonZeroU32::get).unwrap_or(u32::MAX) }
+	fn new<M: Borrow<[u8]>>(rom: &'a Rom<M>, pos: u32) -> Self {
+		ModuleChain { rom: rom.as_slice32(), pos, endian: rom.config.endian, cached: None,
+			fields: ModuleFields::NONE }
 	}
 
-	#[inline]
-	fn in_range(&self) -> impl Fn(&u32) -> bool {
-		let len = self.rom.len();
-		move |n| *n < len
+	/// Resolves the given header `fields` of each module as it's read from the chain.
+	///
+	/// A [`Module`] remembers each field the first time it's resolved, so repeated accessor
+	/// calls are cheap either way; this only moves that work up front, such as when every
+	/// module will be fully inspected. By default, no fields are resolved in advance.
+	pub fn with_fields(self, fields: ModuleFields) -> Self {
+		ModuleChain { fields, ..self }
+	}
+
+	/// Reads the next chain entry, and advances past it.
+	///
+	/// Returns `None` at the end of the chain, or `Some(Err(_))` (once) if the chain is broken.
+	fn next_entry(&mut self) -> Option<Result<Module<'a>, RomDecodeError>> {
+		if let Some(ref mut cached) = self.cached {
+			let entry = *cached.next()?;
+			return self.read_entry(entry);
+		}
+
+		if self.pos == u32::MAX {
+			return None;
+		}
+
+		let entry = std::mem::replace(&mut self.pos, u32::MAX);
+		let result = self.read_entry(entry);
+		if let Some(Ok(ref module)) = result {
+			self.pos = entry + module.chain_len;
+		}
+		result
+	}
+
+	/// Reads the chain entry at `entry`.
+	fn read_entry(&self, entry: u32) -> Option<Result<Module<'a>, RomDecodeError>> {
+		let broken = RomDecodeError::ModuleChainBroken { module_offset: entry };
+
+		// running off the end of the ROM without a terminator is just as broken as a bad length
+		let Some(module_len) = self.rom.read_word_endian(entry, self.endian)
+		else { return Some(Err(broken)) };
+		if module_len == 0 {
+			return None;
+		}
+
+		// `module_len` includes the chain length word; a value under 4 makes an inverted range
+		let module_start = entry + 4;
+		let Some(bytes) = entry.checked_add(module_len)
+			.and_then(|end| self.rom.subslice(module_start..end))
+		else { return Some(Err(broken)) };
+
+		let module = Module::new(bytes, module_start, module_len, self.endian);
+		if self.fields.contains(ModuleFields::TITLE) {
+			let _ = module.title();
+		}
+		if self.fields.contains(ModuleFields::HEADER_LEN) {
+			module.header_len();
+		}
+		Some(Ok(module))
 	}
 }
 
@@ -258,49 +1137,1642 @@ impl<'a> Iterator for ModuleChain<'a> {
 	type Item = Module<'a>;
 
 	fn next(&mut self) -> Option<Self::Item> {
-		let (module_start, module_len) = (
-			self.pos.checked_add(4)?, self.rom.read_word(self.pos)?
-		);
-
-		if module_len > 0 {
-			self.pos = self.pos.checked_add(module_len)
-				.filter(self.in_range())
-				.unwrap_or(u32::MAX);
-		} else {
-			self.pos = u32::MAX;
-			return None;
-		}
+		self.next_entry()?.ok()
+	}
 
-		// sub 4 to remove chain length word (`module_len` includes this)
-		let r = module_start .. module_start.checked_sub(4)?.saturating_add(module_len);
-		let offset = r.start;
-		Some(Module { bytes: self.rom.subslice(r)?, offset })
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		match self.cached {
+			// every cached entry but the last is a valid module, so they can be skipped unread
+			Some(ref mut cached) if n > 0 => {
+				cached.nth(n - 1)?;
+				self.next()
+			},
+			_ => {
+				for _ in 0..n {
+					self.next()?;
+				}
+				self.next()
+			},
+		}
 	}
 }
 
 impl<'a> FusedIterator for ModuleChain<'a> { }
 
+/// An iterator over each module in the ROM image, which yields an error if the chain is broken.
+///
+/// Returned by [`Rom::try_module_chain`].
+pub struct TryModuleChain<'a> {
+	chain: ModuleChain<'a>,
+	not_found: bool,
+	/// Modules left to yield before reporting `ModuleLimitReached`.
+	remaining: usize,
+}
+
+impl<'a> Iterator for TryModuleChain<'a> {
+	type Item = Result<Module<'a>, RomDecodeError>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if std::mem::take(&mut self.not_found) {
+			return Some(Err(RomDecodeError::UtilityModuleNotFound));
+		}
+
+		let item = self.chain.next_entry()?;
+		if self.remaining == 0 {
+			let module_offset = match item {
+				Ok(ref module) => module.offset - 4,
+				Err(_) => return Some(item),
+			};
+			// nothing is read beyond the limit
+			self.chain.cached = None;
+			self.chain.pos = u32::MAX;
+			return Some(Err(RomDecodeError::ModuleLimitReached { module_offset }));
+		}
+		if item.is_ok() {
+			self.remaining -= 1;
+		}
+		Some(item)
+	}
+}
+
+impl<'a> FusedIterator for TryModuleChain<'a> { }
+
 /// Metadata for a single module in the ROM image.
 pub struct Module<'a> {
 	bytes: &'a Slice32,
 	offset: u32,
+	chain_len: u32,
+	endian: Endian,
+	/// The title, or the offset to report as unterminated, once resolved.
+	title: OnceCell<Result<&'a Slice32, u32>>,
+	header_len: OnceCell<u32>,
 }
 
 impl<'a> Module<'a> {
+	fn new(bytes: &'a Slice32, offset: u32, chain_len: u32, endian: Endian) -> Self {
+		Module { bytes, offset, chain_len, endian, title: OnceCell::new(),
+			header_len: OnceCell::new() }
+	}
+
 	/// Returns a slice over the C-string of this module title.
-	pub fn title(&self) -> Result<&Slice32, RomDecodeError> {
-		self.bytes.read_word(0x10) // get title offset
-			.and_then(|o| self.bytes.subslice_from(o)) // shift slice start to title start
-			.and_then(Slice32::cstr) // reduce to cstr
-			.ok_or(RomDecodeError::UnterminatedCstr)
+	pub fn title(&self) -> Result<&'a Slice32, RomDecodeError> {
+		let title = self.title.get_or_init(|| {
+			let title_offset = self.word(0x10).ok_or(0x10u32)?;
+			self.bytes.subslice_from(title_offset) // shift slice start to title start
+				.and_then(Slice32::cstr) // reduce to cstr
+				.ok_or(title_offset)
+		});
+		title.map_err(|rel|
+			RomDecodeError::UnterminatedCstr { at: self.offset.saturating_add(rel) })
 	}
 
-	/// Returns a slice over the entire module contents.
-	#[inline]
-	pub const fn data(&self) -> &Slice32 { self.bytes }
+	/// Decodes the title as Latin-1, or gives a placeholder of the form `<unnamed@0x…>` if it's
+	/// empty or can't be read.
+	fn lossy_title(&self) -> String {
+		match self.title() {
+			Ok(title) if !title.is_empty() => title.to_latin1_string(),
+			_ => format!("<unnamed@{:#x}>", self.offset),
+		}
+	}
 
-	/// Returns the offset of this module within the ROM image.
-	#[inline]
-	pub const fn offset(&self) -> u32 { self.offset }
-}
+	/// Returns an owned summary of the module, for display or serialisation.
+	///
+	/// This never fails: on a corrupt module, the name falls back to the same placeholder as
+	/// [`Rom::module_names_lossy`], and the other fields to `None` or `false`.
+	pub fn info(&self) -> ModuleInfo {
+		let swi_base = self.swi_chunk_base();
+		ModuleInfo {
+			name: self.lossy_title(),
+			offset: self.offset,
+			length: self.bytes.len(),
+			swi_base,
+			has_commands: self.header_len() > 0x18 && self.header_field(0x18).is_some(),
+			has_swis: swi_base.is_some(),
+			provides_service: self.provides_service(),
+		}
+	}
+
+	/// Returns the absolute offset of the title string within the ROM image.
+	///
+	/// Returns `None` if the title offset in the header is zero or outside the module.
+	pub fn title_offset(&self) -> Option<u32> {
+		self.resolve(4)
+	}
+
+	/// Resolves word `header_word_index` of the module header as an offset into the module,
+	/// returning the corresponding absolute offset within the ROM image.
+	///
+	/// Header words are numbered from 0, so the title offset at byte 0x10 is word 4. Zero means
+	/// the field is unused, so yields `None`, as does an offset outside the module. This works
+	/// for any header word, including ones this crate has no dedicated accessor for; it's up to
+	/// the caller to know that the word is an offset, and that the header is long enough to
+	/// contain it (see [`header_len`](Self::header_len)).
+	pub fn resolve(&self, header_word_index: u32) -> Option<u32> {
+		self.offset.checked_add(self.header_field(header_word_index.checked_mul(4)?)?)
+	}
+
+	/// Reads the header word at byte offset `field` as an offset into the module, as for
+	/// [`resolve`](Self::resolve), but leaves it relative to the module header.
+	fn header_field(&self, field: u32) -> Option<u32> {
+		self.word(field).filter(|&n| n != 0 && n < self.bytes.len())
+	}
+
+	/// Returns the absolute offset of the module's service call handler, from header word 0x0C.
+	///
+	/// Returns `None` if the module has no handler (the word is zero), or the offset is outside
+	/// the module.
+	pub fn service_call_offset(&self) -> Option<u32> {
+		if self.header_len() <= 0x0c {
+			return None;
+		}
+		self.resolve(3)
+	}
 
+	/// Returns `true` if the module has a service call handler, so takes part in service call
+	/// broadcasts.
+	pub fn provides_service(&self) -> bool {
+		self.service_call_offset().is_some()
+	}
+
+	/// Returns the SWI decoding table, from header word 0x24: the group prefix, followed by the
+	/// name of each SWI in the chunk.
+	fn swi_table(&self) -> Option<CStrs<'a>> {
+		if self.header_len() <= 0x24 {
+			return None;
+		}
+		Some(self.bytes.subslice_from(self.header_field(0x24)?)?.cstrs())
+	}
+
+	/// Returns the prefix shared by the module's SWI names, such as `OS` or `Wimp`, from the
+	/// start of its SWI decoding table.
+	///
+	/// Returns `None` if the module has no decoding table (header word 0x24 is zero). The
+	/// individual names are given by [`swi_names`](Self::swi_names).
+	pub fn swi_prefix(&self) -> Option<&'a Slice32> {
+		self.swi_table()?.next()
+	}
+
+	/// Returns an iterator over the names in the module's SWI decoding table, without the
+	/// [prefix](Self::swi_prefix), in SWI number order.
+	///
+	/// Returns `None` if the module has no decoding table.
+	pub fn swi_names(&self) -> Option<CStrs<'a>> {
+		let mut table = self.swi_table()?;
+		table.next()?;
+		Some(table)
+	}
+
+	/// Returns `true` if the module header is plausible: its title offset points to a terminated
+	/// string within the module, and at least one of the start, initialisation, finalisation or
+	/// service call entry offsets is a word-aligned offset within the module.
+	///
+	/// This only reads the header and title, so it's a cheap way to tell a real module from
+	/// arbitrary bytes.
+	pub fn has_valid_header(&self) -> bool {
+		const CODE_ENTRY_FIELDS: [u32; 4] = [0x00, 0x04, 0x08, 0x0c];
+
+		let title_ok = self.word(0x10).is_some_and(|n| n != 0) && self.title().is_ok();
+		title_ok && CODE_ENTRY_FIELDS.into_iter().any(|field| matches!(self.word(field),
+			Some(offset) if offset != 0 && offset & 3 == 0 && offset < self.bytes.len()))
+	}
+
+	/// Returns the module flags word, if the module header is new enough to include one.
+	///
+	/// Header word 0x30 holds the offset of the flags word. If that offset is zero, the module
+	/// declares no flags, so this returns `Some(0)`.
+	pub fn flags(&self) -> Option<u32> {
+		if self.header_len() <= 0x30 {
+			return None;
+		}
+
+		match self.word(0x30)? {
+			0 => Some(0),
+			offset => self.word(offset),
+		}
+	}
+
+	/// Returns the base SWI number of the module's SWI chunk, from header word 0x1C.
+	///
+	/// `None` means the module provides no SWIs (the word is zero, or the header is too short to
+	/// contain it).
+	pub fn swi_chunk_base(&self) -> Option<u32> {
+		if self.header_len() <= 0x1c {
+			return None;
+		}
+		self.word(0x1c).filter(|&n| n != 0)
+	}
+
+	/// Returns whether the module declares itself safe to run on a 32-bit-only kernel, via bit 0
+	/// of its [flags](Self::flags).
+	///
+	/// `None` means the header is too old to say; such modules predate 32-bit kernels.
+	pub fn is_32bit_compatible(&self) -> Option<bool> {
+		self.flags_decoded().map(|f| f.contains(ModuleFlags::THIRTY_TWO_BIT))
+	}
+
+	/// Returns the module [flags](Self::flags) as a [`ModuleFlags`] set.
+	pub fn flags_decoded(&self) -> Option<ModuleFlags> {
+		self.flags().map(ModuleFlags)
+	}
+
+	/// Returns the module contents after its header, i.e. the code and data the header points to.
+	///
+	/// The header has no length field of its own, so its length is inferred: a module's code,
+	/// title and other data are all placed after the header, so the smallest non-zero offset
+	/// stored in the header (usually the title offset) marks its end. The header is never taken
+	/// to extend beyond the flags word at 0x30, which is the last recognised field.
+	pub fn payload(&self) -> &'a Slice32 {
+		self.bytes.subslice_from(self.header_len()).unwrap()
+	}
+
+	/// Reads a word from the module, in the ROM's byte order.
+	fn word(&self, at: u32) -> Option<u32> {
+		self.bytes.read_word_endian(at, self.endian)
+	}
+
+	/// Infers the length of the module header, in bytes.
+	///
+	/// Headers grew over time (the SWI decoding code offset at 0x28, the messages file at 0x2C
+	/// and the flags offset at 0x30 were all later additions), and nothing in a header records
+	/// its own length. Instead, since everything a header points to follows the header, the
+	/// smallest non-zero offset stored in it (typically the title) bounds its length. Offsets
+	/// beyond the module are ignored, as is the SWI chunk number at 0x1C.
+	///
+	/// The result is a multiple of 4 and at most 0x34, the longest header known. An old module
+	/// whose data happens to start at or after 0x34 is therefore reported with a 0x34-byte
+	/// header, and newer fields read as whatever data is there.
+	pub fn header_len(&self) -> u32 {
+		*self.header_len.get_or_init(|| self.infer_header_len())
+	}
+
+	fn infer_header_len(&self) -> u32 {
+		// every header word that is an offset into the module; 0x1c is the SWI chunk number
+		const OFFSET_FIELDS: [u32; 12] =
+			[0x00, 0x04, 0x08, 0x0c, 0x10, 0x14, 0x18, 0x20, 0x24, 0x28, 0x2c, 0x30];
+		const MAX_HEADER_LEN: u32 = 0x34;
+
+		let mut len = MAX_HEADER_LEN.min(round_down_to_word(self.bytes.len()));
+		for field in OFFSET_FIELDS {
+			if field >= len {
+				break;
+			}
+			match self.word(field) {
+				Some(offset) if offset != 0 && offset < self.bytes.len() => {
+					len = len.min(round_down_to_word(offset));
+				},
+				_ => {},
+			}
+		}
+		len
+	}
+
+	/// Returns a slice over the entire module contents.
+	///
+	/// This borrows directly from the ROM's backing storage (whatever `M` is), so no bytes are
+	/// copied, and the module cannot outlive the [`Rom`] it came from.
+	#[inline]
+	pub const fn data(&self) -> &'a Slice32 { self.bytes }
+
+	/// Writes the module contents to `w`, straight from the ROM's backing storage.
+	pub fn write_to<W: Write>(&self, mut w: W) -> io::Result<()> {
+		w.write_all(self.bytes.as_ref())
+	}
+
+	/// Writes the module contents to a new file at `path`, replacing any existing file.
+	pub fn extract_to_file<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+		self.write_to(std::fs::File::create(path)?)
+	}
+
+	/// Returns the offset of this module within the ROM image.
+	#[inline]
+	pub const fn offset(&self) -> u32 { self.offset }
+
+	/// Returns the chain length word that precedes this module in the ROM image.
+	///
+	/// The chain length counts the length word itself as well as the module body, so it is
+	/// always 4 more than `self.data().len()`. Adding it to the position of the length word
+	/// (`self.offset() - 4`) gives the position of the next chain entry.
+	#[inline]
+	pub const fn chain_length(&self) -> u32 { self.chain_len }
+
+	/// Returns the position of the chain entry (i.e. the length word) that follows this module.
+	///
+	/// This is where the chain iterator continues from; a zero word there ends the chain.
+	pub fn next_offset(&self) -> Option<u32> {
+		self.offset.checked_sub(4)?.checked_add(self.chain_len)
+	}
+
+	/// Copies the module out of the ROM image, so it can outlive the [`Rom`].
+	pub fn to_owned(&self) -> OwnedModule {
+		OwnedModule {
+			bytes: self.bytes.to_boxed(),
+			offset: self.offset,
+			chain_len: self.chain_len,
+			endian: self.endian,
+		}
+	}
+}
+
+/// The flags word of a module header, as returned by [`Module::flags_decoded`].
+///
+/// Only bit 0 has a documented meaning. The other bits are reserved, but are kept, so
+/// [`unknown_bits`](Self::unknown_bits) can show a module using them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ModuleFlags(u32);
+
+impl ModuleFlags {
+	/// The module is safe to run on a 32-bit-only kernel.
+	pub const THIRTY_TWO_BIT: Self = Self(1 << 0);
+	/// Every flag with a known meaning.
+	pub const KNOWN: Self = Self::THIRTY_TWO_BIT;
+
+	/// Creates a set of flags from a raw flags word.
+	pub const fn from_bits(bits: u32) -> Self { Self(bits) }
+
+	/// Returns the raw flags word.
+	pub const fn bits(self) -> u32 { self.0 }
+
+	/// Returns `true` if every flag in `other` is also in `self`.
+	pub const fn contains(self, other: Self) -> bool {
+		self.0 & other.0 == other.0
+	}
+
+	/// Returns the bits that are set, but have no known meaning.
+	pub const fn unknown_bits(self) -> u32 {
+		self.0 & !Self::KNOWN.0
+	}
+}
+
+/// An owned summary of a single module, as returned by [`Module::info`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleInfo {
+	/// The module title, decoded as Latin-1, or a placeholder if it can't be read.
+	pub name: String,
+	/// The offset of the module within the ROM image.
+	pub offset: u32,
+	/// The length of the module, in bytes, excluding its chain length word.
+	pub length: u32,
+	/// The base SWI number of the module's SWI chunk, if it has one.
+	pub swi_base: Option<u32>,
+	/// Whether the module has a help and command keyword table.
+	pub has_commands: bool,
+	/// Whether the module provides SWIs.
+	pub has_swis: bool,
+	/// Whether the module has a service call handler.
+	pub provides_service: bool,
+}
+
+/// A copy of a single module from the ROM image, which doesn't borrow the [`Rom`].
+///
+/// Created by [`Module::to_owned`]. Use [`as_module`](Self::as_module) to reach the full set
+/// of [`Module`] accessors; offsets still refer to the ROM image the module was copied from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnedModule {
+	bytes: Box<Slice32>,
+	offset: u32,
+	chain_len: u32,
+	endian: Endian,
+}
+
+impl OwnedModule {
+	/// Borrows `self` as a [`Module`].
+	pub fn as_module(&self) -> Module<'_> {
+		Module::new(&self.bytes, self.offset, self.chain_len, self.endian)
+	}
+
+	/// Returns a slice over the C-string of this module title.
+	pub fn title(&self) -> Result<&Slice32, RomDecodeError> {
+		self.as_module().title()
+	}
+
+	/// Returns the inferred length of the module header; see [`Module::header_len`].
+	pub fn header_len(&self) -> u32 {
+		self.as_module().header_len()
+	}
+
+	/// Returns a slice over the entire module contents.
+	pub fn data(&self) -> &Slice32 { &self.bytes }
+
+	/// Returns the offset of this module within the ROM image it was copied from.
+	pub fn offset(&self) -> u32 { self.offset }
+}
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Offset of the first module's chain entry in ROMs from [`build_rom`].
+	const CHAIN_START: u32 = 0x40;
+	/// Length of the module header built by [`module`]; the title follows it.
+	const HEADER_LEN: u32 = 0x34;
+	/// Length of the 0xff fill between the module chain and footer in [`build_rom`].
+	const FILL_LEN: u32 = 0x100;
+
+	/// Builds a module body (without its chain length word) with the given title, then
+	/// overrides header words with `fields`, as `(header offset, value)` pairs.
+	fn module(title: &[u8], fields: &[(u32, u32)]) -> Vec<u8> {
+		let mut body = vec![0u8; HEADER_LEN as usize];
+		body.extend_from_slice(title);
+		body.push(0);
+		body.resize((body.len() + 3) & !3, 0);
+
+		let mut set = |at: u32, value: u32| body[at as usize .. at as usize + 4]
+			.copy_from_slice(&value.to_le_bytes());
+		set(0x04, HEADER_LEN); // init entry; the title stands in for code
+		set(0x10, HEADER_LEN);
+		for &(at, value) in fields {
+			set(at, value);
+		}
+		body
+	}
+
+	/// Assembles a ROM image: an entry branch, the kernel marker, the given modules as a
+	/// chain starting at [`CHAIN_START`], [`FILL_LEN`] bytes of 0xff padding, then a 12-byte
+	/// zeroed footer.
+	fn build_rom(modules: &[Vec<u8>]) -> Rom {
+		let mut data = vec![0u8; CHAIN_START as usize];
+		data[0..4].copy_from_slice(&0xea000006u32.to_le_bytes()); // B &20
+		data[0x10..0x18].copy_from_slice(b"MODULE#\0");
+
+		for body in modules {
+			data.extend_from_slice(&(body.len() as u32 + 4).to_le_bytes());
+			data.extend_from_slice(body);
+		}
+		data.extend_from_slice(&[0; 4]);
+		data.extend_from_slice(&[0xff; FILL_LEN as usize]);
+		data.extend_from_slice(&[0; 12]);
+		Rom::from_mem(data.into_boxed_slice()).unwrap()
+	}
+
+	fn titles<'a>(chain: impl Iterator<Item = Module<'a>>) -> Vec<Vec<u8>> {
+		chain.map(|m| m.title().unwrap().as_ref().to_vec()).collect()
+	}
+
+	#[test]
+	fn find_cached() {
+		let rom = Rom::from_mem(&b"abcdMODULE#\0efgh"[..]).unwrap();
+		assert_eq!(rom.find_cached(b"MODULE#\0"), Some(4));
+		assert_eq!(rom.find_cached(b"MODULE#\0"), Some(4));
+		assert_eq!(rom.find_cached(b"absent"), None);
+		assert_eq!(rom.find_cache.borrow().len(), 2);
+	}
+
+	#[test]
+	fn module_chain_from() {
+		let rom = build_rom(&[
+			module(b"UtilityModule", &[]),
+			module(b"Second", &[]),
+			module(b"Third", &[]),
+		]);
+		assert_eq!(rom.module_chain_start().map(NonZeroU32::get), Some(CHAIN_START));
+		assert_eq!(titles(rom.module_chain()),
+			[&b"UtilityModule"[..], b"Second", b"Third"]);
+
+		let second = rom.module_chain().nth(1).unwrap().offset() - 4;
+		assert_eq!(titles(rom.module_chain_from(second)), [&b"Second"[..], b"Third"]);
+		assert_eq!(rom.module_chain_from(second + 1).count(), 0);
+		assert_eq!(rom.module_chain_from(rom.len() + 4).count(), 0);
+	}
+
+	#[test]
+	fn module_linkage() {
+		let rom = build_rom(&[module(b"UtilityModule", &[]), module(b"Longer title", &[])]);
+		let modules: Vec<_> = rom.module_chain().collect();
+
+		assert_eq!(modules[0].chain_length(), modules[0].data().len() + 4);
+		assert_eq!(modules[0].next_offset(), Some(modules[1].offset() - 4));
+		assert_eq!(rom.read_word(modules[1].next_offset().unwrap()), Some(0));
+	}
+
+	#[test]
+	fn layout() {
+		let rom = build_rom(&[module(b"UtilityModule", &[]), module(b"Second", &[])]);
+		let modules: Vec<_> = rom.module_chain().collect();
+		let (first, second) = (modules[0].offset() - 4, modules[1].offset() - 4);
+		let padding_start = modules[1].next_offset().unwrap();
+		let len = rom.len();
+
+		assert_eq!(rom.layout(), [
+			Region { kind: RegionKind::Bootloader, range: 0..0x18 },
+			Region { kind: RegionKind::Kernel, range: 0x18..CHAIN_START },
+			Region { kind: RegionKind::Module, range: first..second },
+			Region { kind: RegionKind::Module, range: second..padding_start },
+			Region { kind: RegionKind::Padding, range: padding_start..(len - 12) },
+			Region { kind: RegionKind::Footer, range: (len - 12)..len },
+		]);
+		assert_eq!(rom.footer().map(Slice32::len), Some(12));
+		assert_eq!(rom.kernel().map(Slice32::len), Some(CHAIN_START - 0x18));
+		assert_eq!(rom.kernel_end(), Some(CHAIN_START));
+	}
+
+	#[test]
+	fn layout_into_footer() {
+		// cut the image off just after the chain terminator, so the last module runs into
+		// the footer
+		let full = build_rom(&[module(b"UtilityModule", &[])]);
+		let end = full.module_chain().next().unwrap().next_offset().unwrap();
+		let rom = Rom::from_mem(Box::<[u8]>::from(&full.as_slice()[.. end as usize + 4])).unwrap();
+		let len = rom.len();
+
+		assert_eq!(rom.layout(), [
+			Region { kind: RegionKind::Bootloader, range: 0..0x18 },
+			Region { kind: RegionKind::Kernel, range: 0x18..CHAIN_START },
+			Region { kind: RegionKind::Module, range: CHAIN_START..end },
+			Region { kind: RegionKind::Overlap, range: (len - 12)..end },
+			Region { kind: RegionKind::Footer, range: end..len },
+		]);
+	}
+
+	#[test]
+	fn layout_kernel_after_chain() {
+		// move the kernel marker into the fill after the module chain
+		let full = build_rom(&[module(b"UtilityModule", &[]), module(b"Second", &[])]);
+		let modules: Vec<_> = full.module_chain().collect();
+		let (first, second) = (modules[0].offset() - 4, modules[1].offset() - 4);
+		let end = modules[1].next_offset().unwrap();
+		let mut data = full.as_slice().to_vec();
+		data[0x10..0x18].fill(0);
+		let marker = end as usize + 8;
+		data[marker .. marker + 8].copy_from_slice(b"MODULE#\0");
+		let rom = Rom::from_mem(data.into_boxed_slice()).unwrap();
+		let (kernel, len) = (marker as u32 + 8, rom.len());
+
+		assert_eq!(rom.kernel_start().map(NonZeroU32::get), Some(kernel));
+		assert_eq!(rom.layout(), [
+			Region { kind: RegionKind::Bootloader, range: 0..kernel },
+			Region { kind: RegionKind::Overlap, range: first..second },
+			Region { kind: RegionKind::Overlap, range: second..end },
+			Region { kind: RegionKind::Padding, range: kernel..(len - 12) },
+			Region { kind: RegionKind::Footer, range: (len - 12)..len },
+		]);
+	}
+
+	#[test]
+	fn padding() {
+		let rom = build_rom(&[module(b"UtilityModule", &[])]);
+		assert_eq!(rom.fill_byte(), Some(0xff));
+		// the zero chain terminator is padding too, but isn't fill
+		assert_eq!(rom.padding_bytes(), FILL_LEN);
+		assert_eq!(Rom::from_mem(&b"0123456789abcdefghijklmn"[..]).unwrap().fill_byte(), None);
+		let expected = (rom.len() - FILL_LEN) as f32 / rom.len() as f32;
+		assert!((rom.utilisation() - expected).abs() < f32::EPSILON);
+	}
+
+	#[test]
+	fn entry_point() {
+		let rom = build_rom(&[module(b"UtilityModule", &[])]);
+		assert_eq!(rom.entry_point(), Some(EntryPoint::Branch(0x20)));
+
+		let rom = Rom::from_mem(&[0x00, 0xf0, 0x9f, 0xe5][..]).unwrap(); // LDR PC, [PC, #0]
+		assert_eq!(rom.entry_point(), Some(EntryPoint::Raw(0xe59ff000)));
+		assert_eq!(Rom::empty().entry_point(), None);
+	}
+
+	#[test]
+	fn looks_valid() {
+		assert!(build_rom(&[module(b"UtilityModule", &[])]).looks_valid());
+		assert!(!build_rom(&[module(b"NotUtilityModule", &[])]).looks_valid());
+		assert!(!Rom::from_mem(&[0u8; 64][..]).unwrap().looks_valid());
+	}
+
+	#[test]
+	fn load_size_errors() {
+		assert!(matches!(Rom::from_mem(&[0u8; 6][..]),
+			Err(RomLoadError::RomMisaligned { actual: 6 })));
+
+		let oversized = vec![0u8; ROM_LIMIT as usize + 4];
+		assert!(matches!(Rom::from_mem(&oversized[..]),
+			Err(RomLoadError::RomTooLarge { actual, limit: ROM_LIMIT })
+				if actual == ROM_LIMIT as u64 + 4));
+	}
+
+	#[test]
+	fn decode_error_offsets() {
+		let rom = build_rom(&[
+			module(b"UtilityModule", &[]),
+			module(b"Bad title", &[(0x10, 0x1000)]),
+		]);
+		let bad = rom.module_chain().nth(1).unwrap();
+		assert_eq!(bad.title(),
+			Err(RomDecodeError::UnterminatedCstr { at: bad.offset() + 0x1000 }));
+
+		// skip the terminator, into the padding
+		let entry = rom.module_chain().nth(1).unwrap().next_offset().unwrap() + 4;
+		let mut chain = rom.module_chain_from(entry);
+		assert_eq!(chain.next_entry().unwrap().err(),
+			Some(RomDecodeError::ModuleChainBroken { module_offset: entry }));
+		assert!(chain.next_entry().is_none());
+	}
+
+	#[test]
+	fn try_module_chain() {
+		let rom = build_rom(&[module(b"UtilityModule", &[]), module(b"Second", &[])]);
+		assert!(rom.try_module_chain().map(|m| m.map(|m| m.offset())).eq(
+			rom.module_chain().map(|m| Ok(m.offset()))));
+
+		// point the second module's length word past the end of the ROM
+		let mut data = rom.as_slice().to_vec();
+		let second = rom.module_chain().nth(1).unwrap().offset() - 4;
+		data[second as usize..][..4].copy_from_slice(&0x10000u32.to_le_bytes());
+		let rom = Rom::from_mem(data).unwrap();
+
+		let results: Vec<_> = rom.try_module_chain().collect();
+		assert_eq!(results.len(), 2);
+		assert!(results[0].is_ok());
+		assert_eq!(results[1].as_ref().err(),
+			Some(&RomDecodeError::ModuleChainBroken { module_offset: second }));
+
+		let results: Vec<_> = Rom::from_mem(&[0u8; 16][..]).unwrap().try_module_chain()
+			.map(|r| r.err()).collect();
+		assert_eq!(results, [Some(RomDecodeError::UtilityModuleNotFound)]);
+	}
+
+	#[test]
+	fn crc32_range() {
+		let rom = Rom::from_mem(&b"junk123456789junk"[..16]).unwrap();
+		assert_eq!(rom.crc32_range(4..13), Some(0xcbf43926)); // the standard check value
+		assert_eq!(rom.crc32_range(0..0), Some(0));
+		assert_eq!(rom.crc32_range(4..20), None);
+	}
+
+	#[test]
+	fn skip_prefix() {
+		let data = b"HDR!\x01\0\0\0\x02\0\0\0";
+		let rom = Rom::from_mem_at(&data[..], 4).unwrap();
+		assert_eq!(rom.as_slice(), &data[4..]);
+		assert_eq!(rom.as_slice32().read_word(0), Some(1));
+
+		assert!(matches!(Rom::from_mem_at(&data[..], 2),
+			Err(RomLoadError::RomMisaligned { actual: 10 })));
+		assert!(matches!(Rom::from_mem_at(&data[..], 16),
+			Err(RomLoadError::SkipBeyondEnd { skip: 16, actual: 12 })));
+	}
+
+	#[test]
+	fn builder() {
+		let data = [0u8; 0x24];
+		let rom = RomBuilder::new().skip(4).logical_base(0x0340_0000).from_mem(&data[..]).unwrap();
+		assert_eq!(rom.as_slice32().len(), 0x20);
+		assert_eq!(rom.offset_to_address(0x10), Some(0x0340_0010));
+		assert_eq!(rom.offset_to_address(0x20), None);
+		assert_eq!(rom.address_to_offset(0x0340_001c), Some(0x1c));
+		assert_eq!(rom.address_to_offset(0x033f_fffc), None);
+
+		assert!(matches!(RomBuilder::new().size_limit(0x20).from_mem(&data[..]),
+			Err(RomLoadError::RomTooLarge { actual: 0x24, limit: 0x20 })));
+	}
+
+	#[test]
+	fn module_names() {
+		let rom = build_rom(&[
+			module(b"UtilityModule", &[]),
+			module(b"Broken", &[(0x10, 0x1000)]),
+			module(b"Third", &[]),
+		]);
+		let names: Vec<_> = rom.module_names()
+			.map(|n| n.map(|n| n.as_ref().to_vec()).ok())
+			.collect();
+		assert_eq!(names, [Some(b"UtilityModule".to_vec()), None, Some(b"Third".to_vec())]);
+	}
+
+	#[test]
+	fn module_names_lossy() {
+		let rom = build_rom(&[
+			module(b"UtilityModule", &[]),
+			module(b"Broken", &[(0x10, 0x1000)]),
+			module(b"", &[]),
+			module(b"Caf\xe9", &[]),
+		]);
+		let offsets: Vec<_> = rom.module_chain().map(|m| m.offset()).collect();
+		assert_eq!(rom.module_names_lossy(), [
+			"UtilityModule".to_owned(),
+			format!("<unnamed@{:#x}>", offsets[1]),
+			format!("<unnamed@{:#x}>", offsets[2]),
+			"Caf\u{e9}".to_owned(),
+		]);
+	}
+
+	#[test]
+	fn module_name_set() {
+		let a = build_rom(&[module(b"UtilityModule", &[]), module(b"Podule", &[])]);
+		let b = build_rom(&[module(b"UtilityModule", &[]), module(b"FileSwitch", &[])]);
+		let (a, b) = (a.module_name_set(), b.module_name_set());
+
+		let common: Vec<_> = a.intersection(&b).map(|n| &n[..]).collect();
+		assert_eq!(common, [b"UtilityModule"]);
+		let only_a: Vec<_> = a.difference(&b).map(|n| &n[..]).collect();
+		assert_eq!(only_a, [b"Podule"]);
+	}
+
+	#[test]
+	fn module_flags() {
+		let mut flagged = module(b"Flagged", &[]);
+		let flags_at = flagged.len() as u32;
+		flagged.extend_from_slice(&1u32.to_le_bytes());
+		flagged[0x30..0x34].copy_from_slice(&flags_at.to_le_bytes());
+
+		let rom = build_rom(&[
+			module(b"UtilityModule", &[]),
+			flagged,
+			module(b"Old", &[(0x10, 0x2c)]), // title straight after the SWI decoding code word
+		]);
+		let modules: Vec<_> = rom.module_chain().collect();
+
+		assert_eq!(modules[0].flags(), Some(0));
+		assert_eq!(modules[0].is_32bit_compatible(), Some(false));
+		assert_eq!(modules[1].flags(), Some(1));
+		assert_eq!(modules[1].is_32bit_compatible(), Some(true));
+		assert_eq!(modules[2].flags(), None);
+		assert_eq!(modules[2].is_32bit_compatible(), None);
+	}
+
+	#[test]
+	fn banks() {
+		let bank = build_rom(&[module(b"UtilityModule", &[]), module(b"Second", &[])]);
+		let mut data = bank.as_slice().to_vec();
+		data.extend_from_slice(bank.as_slice());
+		let rom = Rom::from_mem(data).unwrap();
+
+		let banks: Vec<_> = rom.banks(bank.len()).unwrap().collect();
+		assert_eq!(banks.len(), 2);
+		assert_eq!(banks[1].module_chain().count(), 2);
+		assert_eq!(banks[1].module_chain_start(), bank.module_chain_start());
+
+		assert!(rom.banks(0).is_none());
+		assert!(rom.banks(bank.len() - 4).is_none());
+	}
+
+	#[test]
+	fn find_all_kernels() {
+		let bank = build_rom(&[module(b"UtilityModule", &[])]);
+		assert_eq!(bank.find_all_kernels(), [0x10]);
+		assert_eq!(bank.kernel_marker_offset(), Some(0x10));
+		assert_eq!(Rom::from_mem(&[0u8; 16][..]).unwrap().kernel_marker_offset(), None);
+
+		let mut data = bank.as_slice().to_vec();
+		data.extend_from_slice(bank.as_slice());
+		let rom = Rom::from_mem(data).unwrap();
+		assert_eq!(rom.find_all_kernels(), [0x10, bank.len() + 0x10]);
+		assert_eq!(rom.kernel_start().map(Offset::get), Some(0x18));
+	}
+
+	#[test]
+	fn payload() {
+		let rom = build_rom(&[
+			module(b"UtilityModule", &[]),
+			module(b"Old", &[(0x10, 0x2c)]),
+			module(b"Code", &[(0x00, 0x3c)]),
+		]);
+		let modules: Vec<_> = rom.module_chain().collect();
+
+		assert_eq!(modules[0].header_len(), 0x34);
+		assert_eq!(modules[1].header_len(), 0x2c);
+		assert_eq!(modules[2].header_len(), 0x34);
+
+		assert_eq!(modules[0].payload(), b"UtilityModule\0\0\0".as_slice());
+		assert_eq!(modules[1].payload().len(), modules[1].data().len() - 0x2c);
+		assert_eq!(modules[2].payload(), b"Code\0\0\0\0".as_slice());
+	}
+
+	#[test]
+	fn modules_where() {
+		let rom = build_rom(&[
+			module(b"UtilityModule", &[]),
+			module(b"Provider", &[(0x1c, 0x4_0000)]),
+			module(b"Short", &[(0x10, 0x1c), (0x1c, 0x4_0040)]),
+		]);
+		let modules: Vec<_> = rom.module_chain().collect();
+		assert_eq!(modules[0].swi_chunk_base(), None);
+		assert_eq!(modules[1].swi_chunk_base(), Some(0x4_0000));
+		assert_eq!(modules[2].swi_chunk_base(), None);
+
+		let providers = rom.modules_where(|m| m.swi_chunk_base().is_some());
+		assert_eq!(titles(providers), [b"Provider"]);
+	}
+
+	#[test]
+	fn total_module_bytes() {
+		let utility = module(b"UtilityModule", &[]);
+		let second = module(b"Second", &[]);
+		let expected = (utility.len() + second.len() + 8) as u32;
+		let rom = build_rom(&[utility, second]);
+		assert_eq!(rom.total_module_bytes(), expected);
+
+		// break the chain after the first module
+		let mut data = rom.as_slice().to_vec();
+		let second_entry = rom.module_chain().next().unwrap().next_offset().unwrap() as usize;
+		data[second_entry .. second_entry + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+		let broken = Rom::from_mem(data).unwrap();
+		assert_eq!(broken.total_module_bytes(), rom.module_chain().next().unwrap().chain_length());
+	}
+
+	#[test]
+	fn big_endian() {
+		let le = build_rom(&[
+			module(b"UtilityModule", &[]),
+			module(b"Second", &[(0x1c, 0x4_0000)]),
+		]);
+
+		// swap every word that isn't text: the entry branch, chain lengths and module headers
+		let mut data = le.as_slice().to_vec();
+		let mut swap = |at: u32| data[at as usize .. at as usize + 4].reverse();
+		swap(0);
+		for m in le.module_chain() {
+			swap(m.offset() - 4);
+			(0 .. HEADER_LEN).step_by(4).for_each(|field| swap(m.offset() + field));
+		}
+		let be = RomBuilder::new().endianness(Endian::Big).from_mem(data.as_slice()).unwrap();
+
+		assert_eq!(be.endianness(), Endian::Big);
+		assert_eq!(be.entry_point(), le.entry_point());
+		assert_eq!(be.module_chain_start(), le.module_chain_start());
+		assert_eq!(titles(be.module_chain()), titles(le.module_chain()));
+		let second = be.module_chain().nth(1).unwrap();
+		assert_eq!(second.swi_chunk_base(), Some(0x4_0000));
+
+		let misread = Rom::from_mem(data.as_slice()).unwrap();
+		assert_eq!(misread.module_chain().count(), 0);
+	}
+
+	#[test]
+	fn module_by_swi_base() {
+		let rom = build_rom(&[
+			module(b"UtilityModule", &[]),
+			module(b"First", &[(0x1c, 0x4_0000)]),
+			module(b"Second", &[(0x1c, 0x4_0040)]),
+		]);
+
+		let title = |base| rom.module_by_swi_base(base).map(|m| m.title().unwrap().as_ref());
+		assert_eq!(title(0x4_0000), Some(&b"First"[..]));
+		assert_eq!(title(0x4_0040), Some(&b"Second"[..]));
+		assert_eq!(title(0x4_0080), None);
+		assert_eq!(title(0), None);
+	}
+
+	#[test]
+	fn has_valid_header() {
+		let rom = build_rom(&[
+			module(b"UtilityModule", &[]),
+			module(b"NoCode", &[(0x04, 0)]),
+			module(b"Unaligned", &[(0x04, 0x35)]),
+			module(b"NoTitle", &[(0x10, 0)]),
+			module(b"FarTitle", &[(0x10, 0x1000)]),
+		]);
+		let valid: Vec<_> = rom.module_chain().map(|m| m.has_valid_header()).collect();
+		assert_eq!(valid, [true, false, false, false, false]);
+
+		assert!(!build_rom(&[module(b"UtilityModule", &[(0x04, 0)])]).looks_valid());
+	}
+
+	#[test]
+	fn cstr_at() {
+		let rom = build_rom(&[module(b"UtilityModule", &[])]);
+		assert_eq!(rom.cstr_at(0x10), Ok(Slice32::new(b"MODULE#").unwrap()));
+		assert_eq!(rom.cstr_at(0x13), Ok(Slice32::new(b"ULE#").unwrap()));
+
+		assert_eq!(rom.cstr_at(rom.len() + 1), Err(RomDecodeError::UnterminatedCstr {
+			at: rom.len() + 1 }));
+
+		let unterminated = Rom::from_mem(&b"abc\0defg"[..8]).unwrap();
+		assert_eq!(unterminated.cstr_at(0), Ok(Slice32::new(b"abc").unwrap()));
+		assert_eq!(unterminated.cstr_at(4), Err(RomDecodeError::UnterminatedCstr { at: 4 }));
+	}
+
+	#[test]
+	fn crc32_with_progress() {
+		let data: Vec<u8> = (0 .. 0x2_8000u32).map(|n| n as u8).collect();
+		let rom = Rom::from_mem(data.as_slice()).unwrap();
+
+		let mut calls = Vec::new();
+		let crc = rom.crc32_with_progress(|done, total| calls.push((done, total)));
+		assert_eq!(crc, rom.crc32());
+		assert_eq!(calls, [(0, 0x2_8000), (0x1_0000, 0x2_8000), (0x2_0000, 0x2_8000),
+			(0x2_8000, 0x2_8000)]);
+	}
+
+	#[test]
+	fn title_offset() {
+		let rom = build_rom(&[
+			module(b"UtilityModule", &[]),
+			module(b"NoTitle", &[(0x10, 0)]),
+			module(b"FarTitle", &[(0x10, 0x1000)]),
+		]);
+		let modules: Vec<_> = rom.module_chain().collect();
+
+		let title_at = modules[0].title_offset().unwrap();
+		assert_eq!(title_at, CHAIN_START + 4 + HEADER_LEN);
+		assert_eq!(rom.cstr_at(title_at), modules[0].title());
+		assert_eq!(modules[1].title_offset(), None);
+		assert_eq!(modules[2].title_offset(), None);
+	}
+
+	#[test]
+	fn version_string() {
+		let plain = build_rom(&[module(b"UtilityModule", &[])]);
+		assert_eq!(plain.version_name_offset(), None);
+		assert_eq!(plain.version_string(), None);
+
+		// the kernel spans 0x18..CHAIN_START in a test ROM
+		let mut data = plain.as_slice().to_vec();
+		data[0x18 .. 0x20].copy_from_slice(b"RISC OS!");
+		data[0x20 .. 0x33].copy_from_slice(b"RISC OS\t\t3.11 (29S\0");
+		let rom = Rom::from_mem(data).unwrap();
+		assert_eq!(rom.version_name_offset(), Some(0x20));
+		assert_eq!(rom.version_string(), Some(Slice32::new(b"RISC OS\t\t3.11 (29S").unwrap()));
+	}
+
+	#[test]
+	fn try_from() {
+		let data = build_rom(&[module(b"UtilityModule", &[])]).as_slice().to_vec();
+
+		let borrowed: Rom<&Slice32> = data.as_slice().try_into().unwrap();
+		assert!(borrowed.looks_valid());
+		let owned = Rom::try_from(data.clone().into_boxed_slice()).unwrap();
+		assert_eq!(owned.module_chain().count(), 1);
+
+		assert!(matches!(Rom::<&Slice32>::try_from(&data[1..]),
+			Err(RomLoadError::RomMisaligned { .. })));
+		assert!(matches!(Rom::try_from(vec![0u8; 6].into_boxed_slice()),
+			Err(RomLoadError::RomMisaligned { actual: 6 })));
+	}
+
+	#[test]
+	fn eq_hash() {
+		use std::collections::HashSet;
+
+		let a = build_rom(&[module(b"UtilityModule", &[])]);
+		let b = RomBuilder::new().logical_base(0).from_mem(Box::from(a.as_slice())).unwrap();
+		let c = build_rom(&[module(b"UtilityModule", &[]), module(b"Other", &[])]);
+		assert!(a.looks_valid()); // populate a's caches only
+		assert!(a == b);
+		assert!(a != c);
+		assert!(a.as_ref() == a);
+
+		#[allow(clippy::mutable_key_type)] // the cached lookups don't take part in hashing
+		let set: HashSet<_> = [a, b, c].into_iter().collect();
+		assert_eq!(set.len(), 2);
+	}
+
+	#[test]
+	fn export_regions_json() {
+		let rom = build_rom(&[
+			module(b"UtilityModule", &[(0x00, 0x34)]),
+			module(b"Quote\"d\t", &[]),
+			module(b"Broken", &[(0x10, 0x1000)]),
+		]);
+		let offsets: Vec<_> = rom.module_chain().map(|m| m.offset()).collect();
+		let address = |offset| DEFAULT_LOGICAL_BASE + offset;
+		let expected = format!(r#"[
+	{{"name": "UtilityModule", "offset": {}, "length": 68, "address": {}, "entry": {}}},
+	{{"name": "Quote\"d\t", "offset": {}, "length": 64, "address": {}, "entry": null}},
+	{{"name": null, "offset": {}, "length": 60, "address": {}, "entry": null}}
+]"#,
+			offsets[0], address(offsets[0]), address(offsets[0] + 0x34),
+			offsets[1], address(offsets[1]),
+			offsets[2], address(offsets[2]));
+		assert_eq!(rom.export_regions_json(), expected);
+
+		assert_eq!(Rom::from_mem(&[0u8; 16][..]).unwrap().export_regions_json(), "[]");
+	}
+
+	#[test]
+	fn check_length_words() {
+		let modules = [
+			module(b"UtilityModule", &[]),
+			module(b"Skipped", &[]),
+			module(b"Third", &[]),
+		];
+		let rom = build_rom(&modules);
+		assert_eq!(rom.check_length_words(), []);
+
+		// stretch the first length word over the second module; the chain still walks
+		let first_len = modules[0].len() as u32 + 4;
+		let stretched = first_len + modules[1].len() as u32 + 4;
+		let mut data = rom.as_slice().to_vec();
+		data[CHAIN_START as usize ..][.. 4].copy_from_slice(&stretched.to_le_bytes());
+		let rom = Rom::from_mem(data).unwrap();
+		assert_eq!(titles(rom.module_chain()), [&b"UtilityModule"[..], b"Third"]);
+		assert_eq!(rom.check_length_words(), [(CHAIN_START, stretched, first_len)]);
+	}
+
+	#[test]
+	fn from_reader() {
+		let data = build_rom(&[module(b"UtilityModule", &[])]).as_slice().to_vec();
+
+		let rom = Rom::from_reader(data.as_slice()).unwrap();
+		assert_eq!(rom.as_slice(), data);
+		assert!(matches!(Rom::from_reader(&data[1..]), Err(RomLoadError::RomMisaligned { .. })));
+		assert!(matches!(Rom::from_reader(io::repeat(0)),
+			Err(RomLoadError::RomTooLarge { limit: ROM_LIMIT, .. })));
+
+		let sized = Rom::from_reader_sized(data.as_slice(), 16).unwrap();
+		assert_eq!(sized.as_slice(), &data[..16]);
+		assert!(matches!(Rom::from_reader_sized(data.as_slice(), data.len() as u32 + 4),
+			Err(RomLoadError::Io(e)) if e.kind() == io::ErrorKind::UnexpectedEof));
+		assert!(matches!(Rom::from_reader_sized(io::empty(), 6),
+			Err(RomLoadError::RomMisaligned { actual: 6 })));
+	}
+
+	#[test]
+	fn slice() {
+		let rom = build_rom(&[module(b"UtilityModule", &[])]);
+		assert_eq!(rom.slice(0x10 .. 0x17), Some(Slice32::new(b"MODULE#").unwrap()));
+		assert_eq!(rom.slice(0 .. rom.len()).map(Slice32::len), Some(rom.len()));
+		assert_eq!(rom.slice(0 .. rom.len() + 1), None);
+		#[allow(clippy::reversed_empty_ranges)]
+		let reversed = rom.slice(8 .. 4);
+		assert_eq!(reversed, None);
+	}
+
+	#[test]
+	fn chain_cache() {
+		let rom = build_rom(&[
+			module(b"UtilityModule", &[]),
+			module(b"Second", &[]),
+			module(b"Third", &[]),
+		]);
+		let walked = titles(rom.module_chain());
+		assert_eq!(rom.chain_entries.get().map(|e| e.len()), Some(4)); // including terminator
+		assert_eq!(titles(rom.module_chain()), walked);
+		assert_eq!(rom.module_chain().nth(2).unwrap().title().unwrap(), b"Third".as_slice());
+		assert!(rom.module_chain().nth(3).is_none());
+		assert!(rom.module_chain().nth(usize::MAX).is_none());
+		assert!(rom.as_ref().chain_entries.get().is_none());
+
+		// a broken chain still ends with an error
+		let mut data = rom.as_slice().to_vec();
+		let third = rom.module_chain().nth(2).unwrap().offset() as usize - 4;
+		data[third .. third + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+		let broken = Rom::from_mem(data).unwrap();
+		for _ in 0..2 {
+			let results: Vec<_> = broken.try_module_chain().map(|m| m.map(|_| ())).collect();
+			assert_eq!(results, [Ok(()), Ok(()),
+				Err(RomDecodeError::ModuleChainBroken { module_offset: third as u32 })]);
+			assert_eq!(broken.module_chain().nth(2).map(|m| m.offset()), None);
+		}
+	}
+
+	#[test]
+	fn section() {
+		let rom = build_rom(&[module(b"UtilityModule", &[]), module(b"Second", &[])]);
+		let span = |which| rom.section(which).map(|s| {
+			let start = s.as_ref().as_ptr() as usize - rom.as_slice().as_ptr() as usize;
+			start as u32 .. start as u32 + s.len()
+		});
+
+		assert_eq!(span(Section::Bootloader), Some(0 .. 0x18));
+		assert_eq!(span(Section::Kernel), Some(0x18 .. CHAIN_START));
+		let modules_end = rom.module_chain().nth(1).unwrap().next_offset().unwrap();
+		assert_eq!(span(Section::Modules), Some(CHAIN_START .. modules_end));
+		assert_eq!(span(Section::Footer), Some(rom.len() - 12 .. rom.len()));
+
+		let blank = Rom::from_mem(&[0u8; 16][..]).unwrap();
+		assert_eq!(blank.section(Section::Modules), None);
+		assert_eq!(blank.section(Section::Kernel), None);
+	}
+
+	#[test]
+	fn owned_module() {
+		let owned: Vec<OwnedModule> = {
+			let rom = build_rom(&[module(b"UtilityModule", &[]), module(b"Second", &[])]);
+			rom.module_chain().map(|m| m.to_owned()).collect()
+		};
+
+		assert_eq!(owned[1].title(), Ok(Slice32::new(b"Second").unwrap()));
+		assert_eq!(owned[1].header_len(), HEADER_LEN);
+		assert_eq!(owned[0].offset(), CHAIN_START + 4);
+		assert_eq!(owned[0].as_module().next_offset(), Some(owned[1].offset() - 4));
+		assert_eq!(owned[0].data().len() + 4, owned[0].as_module().chain_length());
+	}
+
+	#[test]
+	fn verify_checksum() {
+		let mut data = vec![0u8; 0x5000];
+		let name = b"RISC OS\t\t3.11 (29 Sep 1992)\0";
+		data[0x498c ..][.. name.len()].copy_from_slice(name);
+		let patched = Rom::from_mem(data).unwrap();
+		assert!(matches!(patched.checksum_kind(),
+			Some(ChecksumKind::Catalogued(v)) if v.name_high_level == "RISC OS 3.11"));
+		assert_eq!(patched.verify_checksum(), ChecksumStatus::Invalid);
+
+		let unknown = build_rom(&[module(b"UtilityModule", &[])]);
+		assert!(unknown.checksum_kind().is_none());
+		assert_eq!(unknown.verify_checksum(), ChecksumStatus::UnknownScheme);
+	}
+
+	#[test]
+	fn from_file_crc() {
+		let mut data: Vec<u8> = (0 .. 0x2_0010u32).map(|n| (n * 7) as u8).collect();
+		data[..4].copy_from_slice(b"CRC!");
+		let path = std::env::temp_dir().join(format!("roxtract-crc-{}.rom", std::process::id()));
+		std::fs::write(&path, &data).unwrap();
+
+		let loaded = Rom::from_file_crc(&path);
+		std::fs::remove_file(&path).unwrap();
+		let (rom, crc) = loaded.unwrap();
+		assert_eq!(rom.as_slice(), data);
+		assert_eq!(crc, rom.crc32());
+	}
+
+	#[test]
+	fn swi_names() {
+		let mut decoding = module(b"Font", &[]);
+		let table_at = decoding.len() as u32;
+		decoding.extend_from_slice(b"Font\0CacheAddr\0FindFont\0\0\0\0\0");
+		decoding[0x24..0x28].copy_from_slice(&table_at.to_le_bytes());
+
+		let rom = build_rom(&[module(b"UtilityModule", &[]), decoding]);
+		let modules: Vec<_> = rom.module_chain().collect();
+
+		assert_eq!(modules[0].swi_prefix(), None);
+		assert!(modules[0].swi_names().is_none());
+		assert_eq!(modules[1].swi_prefix(), Some(Slice32::new(b"Font").unwrap()));
+		let names: Vec<&[u8]> = modules[1].swi_names().unwrap().map(AsRef::as_ref).collect();
+		assert_eq!(names, [&b"CacheAddr"[..], b"FindFont"]);
+	}
+
+	#[test]
+	fn words() {
+		let rom = Rom::from_mem(&[1, 0, 0, 0, 0, 0, 0, 2][..]).unwrap();
+		assert_eq!(rom.words().len(), 2);
+		assert_eq!(rom.words().collect::<Vec<_>>(), [(0, 1), (4, 0x0200_0000)]);
+
+		let be = RomBuilder::new().endianness(Endian::Big).from_mem(rom.as_slice()).unwrap();
+		assert_eq!(be.words().collect::<Vec<_>>(), [(0, 0x0100_0000), (4, 2)]);
+	}
+
+	#[test]
+	fn find_pointers_to() {
+		let mut data = vec![0u8; 0x20];
+		data[0x04..0x08].copy_from_slice(&0x18u32.to_le_bytes());
+		data[0x0c..0x10].copy_from_slice(&(DEFAULT_LOGICAL_BASE + 0x18).to_le_bytes());
+		data[0x11..0x15].copy_from_slice(&0x18u32.to_le_bytes()); // unaligned; ignored
+		let rom = Rom::from_mem(data.as_slice()).unwrap();
+
+		assert_eq!(rom.find_pointers_to(0x18, PointerMode::Offset), [0x04]);
+		assert_eq!(rom.find_pointers_to(0x18, PointerMode::Address), [0x0c]);
+		assert_eq!(rom.find_pointers_to(0x40, PointerMode::Address), []);
+
+		let rebased = RomBuilder::new().logical_base(0).from_mem(data.as_slice()).unwrap();
+		assert_eq!(rebased.find_pointers_to(0x18, PointerMode::Address), [0x04]);
+	}
+
+	#[test]
+	fn is_softload() {
+		let rom = build_rom(&[]);
+		assert!(!rom.is_softload());
+
+		let mut data = rom.as_slice().to_vec();
+		data[0x04..0x08].copy_from_slice(&0x0200_8000u32.to_le_bytes());
+		assert!(Rom::from_mem(data.as_slice()).unwrap().is_softload());
+
+		data[0x08..0x0c].copy_from_slice(&(DEFAULT_LOGICAL_BASE + 0x40).to_le_bytes());
+		assert!(!Rom::from_mem(data.as_slice()).unwrap().is_softload());
+
+		// no kernel marker, so no bootloader to inspect
+		data[0x10..0x18].fill(0);
+		assert!(!Rom::from_mem(data.as_slice()).unwrap().is_softload());
+	}
+
+	#[test]
+	fn module_info() {
+		let rom = build_rom(&[
+			module(b"UtilityModule", &[(0x0c, HEADER_LEN), (0x18, HEADER_LEN), (0x1c, 0x40040)]),
+			module(b"Broken", &[(0x10, 0x1000)]),
+		]);
+		let mut chain = rom.module_chain();
+
+		assert_eq!(chain.next().unwrap().info(), ModuleInfo {
+			name: "UtilityModule".to_owned(),
+			offset: CHAIN_START + 4,
+			length: HEADER_LEN + 16,
+			swi_base: Some(0x40040),
+			has_commands: true,
+			has_swis: true,
+			provides_service: true,
+		});
+
+		let broken = chain.next().unwrap();
+		let info = broken.info();
+		assert_eq!(info.name, format!("<unnamed@{:#x}>", broken.offset()));
+		assert!(!info.has_commands && !info.has_swis && !info.provides_service);
+	}
+
+	#[test]
+	fn module_chain_limited() {
+		let rom = build_rom(&[
+			module(b"UtilityModule", &[]),
+			module(b"Podule", &[]),
+			module(b"FileSwitch", &[]),
+		]);
+		let third = rom.module_chain().nth(2).unwrap().offset() - 4;
+
+		let limited: Vec<_> = rom.module_chain_limited(2).map(|m| m.map(|m| m.offset())).collect();
+		assert_eq!(limited.len(), 3);
+		assert_eq!(limited[2], Err(RomDecodeError::ModuleLimitReached { module_offset: third }));
+
+		assert_eq!(rom.module_chain_limited(3).filter(Result::is_ok).count(), 3);
+		assert!(rom.module_chain_limited(3).all(|m| m.is_ok()));
+		assert_eq!(rom.module_chain_limited(0).map(|m| m.err()).collect::<Vec<_>>(),
+			[Some(RomDecodeError::ModuleLimitReached { module_offset: CHAIN_START })]);
+	}
+
+	#[test]
+	fn decode_error_display() {
+		assert_eq!(RomDecodeError::ModuleChainBroken { module_offset: 0x4a120 }.to_string(),
+			"Module chain broken at module offset 0x4a120");
+		assert_eq!(RomDecodeError::UnterminatedCstr { at: 0x4a138 }.to_string(),
+			"Unterminated C-string at 0x4a138");
+		assert!(RomDecodeError::ModuleLimitReached { module_offset: 0x40 }.to_string()
+			.contains("0x40"));
+	}
+
+	#[test]
+	fn module_table_csv() {
+		let rom = build_rom(&[
+			module(b"UtilityModule", &[(0x1c, 0x40040)]),
+			module(b"Quote\"d, too", &[]),
+			module(b"Broken", &[(0x10, 0x1000)]),
+		]);
+		let offsets: Vec<_> = rom.module_chain().map(|m| m.offset()).collect();
+		assert_eq!(rom.module_table_csv(), format!("index,name,offset,length,swi_base
+0,UtilityModule,{},68,262208
+1,\"Quote\"\"d, too\",{},68,
+2,,{},60,
+", offsets[0], offsets[1], offsets[2]));
+
+		assert_eq!(Rom::from_mem(&[0u8; 16][..]).unwrap().module_table_csv(),
+			"index,name,offset,length,swi_base\n");
+	}
+
+	#[test]
+	fn module_fields() {
+		let rom = build_rom(&[module(b"UtilityModule", &[]), module(b"Podule", &[])]);
+		assert!(ModuleFields::ALL.contains(ModuleFields::TITLE | ModuleFields::HEADER_LEN));
+		assert!(!ModuleFields::TITLE.contains(ModuleFields::HEADER_LEN));
+
+		let lazy = rom.module_chain().next().unwrap();
+		assert!(lazy.title.get().is_none() && lazy.header_len.get().is_none());
+
+		let eager = rom.module_chain().with_fields(ModuleFields::ALL).nth(1).unwrap();
+		assert_eq!(eager.title.get(), Some(&Ok(Slice32::new(b"Podule").unwrap())));
+		assert_eq!(eager.header_len.get(), Some(&HEADER_LEN));
+
+		let titled = rom.module_chain().with_fields(ModuleFields::TITLE).next().unwrap();
+		assert!(titled.title.get().is_some() && titled.header_len.get().is_none());
+	}
+
+	#[test]
+	fn from_mem_unchecked() {
+		let rom = build_rom(&[module(b"UtilityModule", &[])]);
+		let rewrapped = unsafe {
+			// SAFETY: `rom` has already been validated
+			Rom::from_mem_unchecked(rom.as_slice())
+		};
+		assert!(rewrapped == rom);
+		assert_eq!(titles(rewrapped.module_chain()), [b"UtilityModule"]);
+	}
+
+	#[test]
+	fn modules_with_prefix() {
+		let rom = build_rom(&[
+			module(b"UtilityModule", &[]),
+			module(b"NetFS", &[]),
+			module(b"NetPrint", &[]),
+			module(b"netstat", &[]),
+			module(b"Broken", &[(0x10, 0x1000)]),
+		]);
+		assert_eq!(titles(rom.modules_with_prefix(b"Net")), [&b"NetFS"[..], b"NetPrint"]);
+		assert_eq!(rom.modules_with_prefix(b"").count(), 4);
+		assert_eq!(rom.modules_with_prefix(b"NetFSX").count(), 0);
+	}
+
+	#[test]
+	fn entropy_windows() {
+		let mut data = vec![0u8; 0x300];
+		for (n, b) in data[0x100..0x200].iter_mut().enumerate() {
+			*b = n as u8;
+		}
+		data[0x200..0x280].fill(0x55);
+		let rom = Rom::from_mem(data.as_slice()).unwrap();
+
+		let windows = rom.entropy_windows(0x100);
+		assert_eq!(windows.iter().map(|w| w.0).collect::<Vec<_>>(), [0, 0x100, 0x200]);
+		assert_eq!(windows[0].1, 0.0);
+		assert!((windows[1].1 - 8.0).abs() < 1e-4);
+		assert!((windows[2].1 - 1.0).abs() < 1e-4);
+
+		assert_eq!(rom.entropy_windows(0x200).len(), 2);
+		assert_eq!(rom.entropy_windows(0x200)[1].0, 0x200);
+		assert!(rom.entropy_windows(0).is_empty());
+	}
+
+	#[test]
+	fn resolve() {
+		let rom = build_rom(&[module(b"UtilityModule", &[(0x00, HEADER_LEN), (0x2c, 0x1000)])]);
+		let m = rom.module_chain().next().unwrap();
+		assert_eq!(m.resolve(0), Some(m.offset() + HEADER_LEN));
+		assert_eq!(m.resolve(4), m.title_offset());
+		assert_eq!(m.resolve(2), None); // zero
+		assert_eq!(m.resolve(11), None); // beyond the module
+		assert_eq!(m.resolve(u32::MAX), None);
+	}
+
+	#[test]
+	fn validate_header() {
+		let rom = build_rom(&[module(b"UtilityModule", &[])]);
+		assert_eq!(rom.validate_header(), Ok(()));
+
+		let mut data = rom.as_slice().to_vec();
+		data[0..4].copy_from_slice(&0xea7fffffu32.to_le_bytes()); // B far beyond the image
+		assert_eq!(Rom::from_mem(data.as_slice()).unwrap().validate_header(),
+			Err(RomDecodeError::ResetNotBranch { word: 0xea7fffff }));
+		data[0..4].copy_from_slice(&0xe59ff000u32.to_le_bytes()); // LDR PC, [PC, #0]
+		assert_eq!(Rom::from_mem(data.as_slice()).unwrap().validate_header(),
+			Err(RomDecodeError::ResetNotBranch { word: 0xe59ff000 }));
+		assert_eq!(Rom::empty().validate_header(),
+			Err(RomDecodeError::ResetNotBranch { word: 0 }));
+
+		data[0..4].copy_from_slice(&0xea000006u32.to_le_bytes());
+		data[0x10..0x18].fill(0);
+		assert_eq!(Rom::from_mem(data.as_slice()).unwrap().validate_header(),
+			Err(RomDecodeError::KernelMarkerNotFound));
+
+		let no_chain = build_rom(&[module(b"NotUtility", &[])]);
+		assert_eq!(no_chain.validate_header(), Err(RomDecodeError::UtilityModuleNotFound));
+	}
+
+	#[test]
+	fn clone_chain() {
+		let rom = build_rom(&[
+			module(b"UtilityModule", &[]),
+			module(b"Podule", &[]),
+			module(b"FileSwitch", &[]),
+		]);
+		for mut chain in [rom.module_chain(), rom.module_chain_from(CHAIN_START)] {
+			chain.next();
+			let mut ahead = chain.clone();
+			assert_eq!(titles(ahead.by_ref().take(1)), [b"Podule"]);
+			assert_eq!(titles(chain), [&b"Podule"[..], b"FileSwitch"]);
+			assert_eq!(titles(ahead), [b"FileSwitch"]);
+		}
+	}
+
+	#[cfg(feature = "sha")]
+	#[test]
+	fn digests() {
+		let rom = Rom::from_mem(&b"abcd"[..]).unwrap();
+		assert_eq!(rom.md5(), hex_bytes("e2fc714c4727ee9395f324cd2e7f331f"));
+		assert_eq!(rom.sha1(), hex_bytes("81fe8bfe87576c3ecb22426f8e57847382917acf"));
+		assert_eq!(rom.sha256(), hex_bytes(
+			"88d4266fd4e6338d13b845fcf289579d209c897823b9217da3e161936f031589"));
+	}
+
+	#[cfg(feature = "sha")]
+	fn hex_bytes<const N: usize>(hex: &str) -> [u8; N] {
+		std::array::from_fn(|n| u8::from_str_radix(&hex[n * 2 .. n * 2 + 2], 16).unwrap())
+	}
+
+	#[cfg(feature = "sha")]
+	#[test]
+	fn datfile_entry() {
+		let rom = Rom::from_mem(&b"abcd"[..]).unwrap();
+		let entry = rom.datfile_entry();
+		assert_eq!(entry.size, 4);
+		assert_eq!(entry.crc32, rom.crc32());
+		assert_eq!(entry.to_xml("RISC OS 3.11 <A & B>.rom"), format!(
+			"<rom name=\"RISC OS 3.11 &lt;A &amp; B&gt;.rom\" size=\"4\" crc=\"{:08x}\" \
+			md5=\"e2fc714c4727ee9395f324cd2e7f331f\" \
+			sha1=\"81fe8bfe87576c3ecb22426f8e57847382917acf\"/>", rom.crc32()));
+	}
+
+	#[test]
+	fn module_at_offset() {
+		let rom = build_rom(&[module(b"UtilityModule", &[]), module(b"Podule", &[])]);
+		let second = rom.module_chain().nth(1).unwrap();
+
+		let found = rom.module_at_offset(second.offset()).unwrap();
+		assert_eq!(found.title(), Ok(Slice32::new(b"Podule").unwrap()));
+		assert_eq!(found.chain_length(), second.chain_length());
+
+		assert!(rom.module_at_offset(second.offset() + 4).is_none());
+		assert!(rom.module_at_offset(0).is_none());
+		assert!(rom.module_at_offset(u32::MAX).is_none());
+	}
+
+	#[test]
+	fn strings() {
+		let rom = Rom::from_mem(&b"\
+			\x01\x02ab\0Kernel\t3.11\0\xa9 Acorn 1992\0\
+			\x05(C) Copyright Acorn Computers Ltd\0\
+			RISC OS 3.11 (29 Sep 1992)\0\0\0Sep 1992\0\0"[..]).unwrap();
+		let all: Vec<_> = rom.strings(4).map(|(at, s)| (at, s.as_ref())).collect();
+		assert_eq!(all, [
+			(5, &b"Kernel\t3.11"[..]),
+			(17, b"\xa9 Acorn 1992"),
+			(31, b"(C) Copyright Acorn Computers Ltd"),
+			(65, b"RISC OS 3.11 (29 Sep 1992)"),
+			(94, b"Sep 1992"),
+		]);
+		assert_eq!(rom.strings(2).next().map(|(at, _)| at), Some(2));
+
+		let offsets = |it: &mut dyn Iterator<Item = (u32, &Slice32)>| it.map(|(at, _)| at)
+			.collect::<Vec<_>>();
+		assert_eq!(offsets(&mut rom.copyright_strings()), [17, 31]);
+		assert_eq!(offsets(&mut rom.date_strings()), [65]);
+	}
+
+	#[test]
+	fn provides_service() {
+		let rom = build_rom(&[
+			module(b"UtilityModule", &[(0x0c, HEADER_LEN)]),
+			module(b"NoService", &[]),
+			module(b"FarService", &[(0x0c, 0x1000)]),
+		]);
+		let modules: Vec<_> = rom.module_chain().collect();
+		assert_eq!(modules[0].service_call_offset(), Some(modules[0].offset() + HEADER_LEN));
+		assert!(modules[0].provides_service());
+		assert!(!modules[1].provides_service());
+		assert!(!modules[2].provides_service());
+		assert_eq!(titles(rom.modules_where(|m| m.provides_service())), [b"UtilityModule"]);
+	}
+
+	#[test]
+	fn module_chain_entries() {
+		let rom = build_rom(&[module(b"UtilityModule", &[]), module(b"Podule", &[])]);
+		let entries: Vec<_> = rom.module_chain_entries()
+			.map(|(len, m)| (len, rom.as_slice32().read_word(m.offset() - 4).unwrap()))
+			.collect();
+		let (first, second) = (HEADER_LEN + 20, HEADER_LEN + 12);
+		assert_eq!(entries, [(first, first), (second, second)]);
+	}
+
+	#[test]
+	fn clone_region() {
+		let inner = build_rom(&[module(b"UtilityModule", &[])]);
+		let mut data = vec![0u8; 0x20];
+		data.extend_from_slice(inner.as_slice());
+		data.extend_from_slice(&[0; 8]);
+		let outer = RomBuilder::new().logical_base(0).from_mem(data.as_slice()).unwrap();
+
+		let end = 0x20 + inner.len();
+		let region = outer.clone_region(0x20 .. end).unwrap();
+		assert!(region == inner);
+		assert_eq!(region.logical_base(), 0);
+		assert_eq!(titles(region.module_chain()), [b"UtilityModule"]);
+
+		assert!(matches!(outer.clone_region(0x20 .. end + 0x10),
+			Err(RomLoadError::RangeOutOfBounds { start: 0x20, .. })));
+		assert!(matches!(outer.clone_region(0x20 .. 0x22),
+			Err(RomLoadError::RomMisaligned { actual: 2 })));
+	}
+
+	#[test]
+	fn keyword_table() {
+		let mut body = module(b"UtilityModule", &[(0x18, HEADER_LEN + 16)]);
+		let word = |body: &mut Vec<u8>, n: u32| body.extend_from_slice(&n.to_le_bytes());
+		// the title takes 16 bytes, the table 52 including its terminator, then the help text
+		let help_at = HEADER_LEN + 16 + 52;
+		body.extend_from_slice(b"Modules\0");
+		for n in [HEADER_LEN, 0x0001_0000, 0, help_at] {
+			word(&mut body, n);
+		}
+		body.extend_from_slice(b"Help\0\0\0\0");
+		for n in [0, 0, 0, 0] {
+			word(&mut body, n);
+		}
+		body.extend_from_slice(b"\0\0\0\0Lists modules\0\0\0");
+		let rom = build_rom(&[body, module(b"NoTable", &[])]);
+		let modules: Vec<_> = rom.module_chain().collect();
+
+		let entries: Vec<_> = modules[0].keyword_table().unwrap().collect();
+		assert_eq!(entries, [
+			KeywordEntry::Command {
+				name: Slice32::new(b"Modules").unwrap(),
+				code_offset: modules[0].offset() + HEADER_LEN,
+				flags: 0x0001_0000,
+				help: Some(Slice32::new(b"Lists modules").unwrap()),
+			},
+			KeywordEntry::HelpText { name: Slice32::new(b"Help").unwrap(), help: None },
+		]);
+		assert!(modules[1].keyword_table().is_none());
+	}
+
+	#[test]
+	fn checksum_layout() {
+		let layout = ChecksumLayout { checksum_words: 1, size_words: 1, reserved_words: 1 };
+		let mut data = vec![0u8; 0x40];
+		data[0..4].copy_from_slice(&0x8000_0001u32.to_le_bytes());
+		data[4..8].copy_from_slice(&0x8000_0002u32.to_le_bytes());
+		data[0x38..0x3c].copy_from_slice(&0x40u32.to_le_bytes());
+		data[0x3c..0x40].copy_from_slice(&3u32.to_le_bytes());
+
+		let rom = Rom::from_mem(data.as_slice()).unwrap();
+		assert_eq!(rom.checksum_layout(), None);
+		assert_eq!(rom.stored_checksum(), None);
+
+		let rom = rom.with_checksum_layout(layout);
+		assert!(matches!(rom.checksum_kind(), Some(ChecksumKind::Footer(l)) if l == layout));
+		assert_eq!(rom.stored_checksum(), Some(3));
+		// the footer algorithm isn't known, so a layout never gives a verdict
+		assert_eq!(rom.verify_checksum(), ChecksumStatus::UnknownScheme);
+
+		let empty = ChecksumLayout { checksum_words: 0, size_words: 0, reserved_words: 3 };
+		let rom = Rom::from_mem(data.as_slice()).unwrap().with_checksum_layout(empty);
+		assert_eq!(rom.stored_checksum(), None);
+		let huge = ChecksumLayout { checksum_words: 0x100, size_words: 0, reserved_words: 0 };
+		let rom = Rom::from_mem(data.as_slice()).unwrap().with_checksum_layout(huge);
+		assert_eq!(rom.stored_checksum(), None);
+	}
+
+	#[test]
+	fn is_module_chain_start() {
+		let mut data = build_rom(&[module(b"UtilityModule", &[]), module(b"Podule", &[])])
+			.as_slice().to_vec();
+		// a mention of the title in the kernel, and a word that happens to point at it
+		data[0x20..0x2e].copy_from_slice(b"UtilityModule\0");
+		data[0x18..0x1c].copy_from_slice(&0x18u32.to_le_bytes());
+		let rom = Rom::from_mem(data.as_slice()).unwrap();
+
+		assert!(!rom.is_module_chain_start(0x04));
+		assert!(rom.is_module_chain_start(CHAIN_START));
+		assert!(!rom.is_module_chain_start(rom.module_chain().nth(1).unwrap().offset() - 4));
+		assert!(!rom.is_module_chain_start(u32::MAX));
+		assert_eq!(rom.module_chain_start().map(NonZeroU32::get), Some(CHAIN_START));
+		assert_eq!(titles(rom.module_chain()), [&b"UtilityModule"[..], b"Podule"]);
+	}
+
+	#[test]
+	fn module_header_raw() {
+		let rom = build_rom(&[module(b"UtilityModule", &[(0x1c, 0x40040)])]);
+		let raw = rom.module_header_raw(&rom.module_chain().next().unwrap());
+		assert_eq!(raw[1], Some(HEADER_LEN));
+		assert_eq!(raw[4], Some(HEADER_LEN));
+		assert_eq!(raw[7], Some(0x40040));
+		assert_eq!(raw[11], Some(0));
+
+		// a one-word module, so only the first header word is in range
+		let short = Rom::from_mem(&[8, 0, 0, 0, 1, 2, 3, 4, 0, 0, 0, 0][..]).unwrap();
+		let module = short.module_chain_from(0).next().unwrap();
+		let raw = short.module_header_raw(&module);
+		assert_eq!(raw[0], Some(0x04030201));
+		assert!(raw[1..].iter().all(Option::is_none));
+	}
+
+	#[test]
+	fn flags_decoded() {
+		let flagged = |title: &[u8], flags: u32| {
+			let mut body = module(title, &[]);
+			let flags_at = body.len() as u32;
+			body.extend_from_slice(&flags.to_le_bytes());
+			body[0x30..0x34].copy_from_slice(&flags_at.to_le_bytes());
+			body
+		};
+		let rom = build_rom(&[
+			flagged(b"UtilityModule", 1),
+			flagged(b"Reserved", 0x8000_0002),
+			module(b"NoFlags", &[]),
+		]);
+		let flags: Vec<_> = rom.module_chain().map(|m| m.flags_decoded()).collect();
+
+		let clean = flags[0].unwrap();
+		assert!(clean.contains(ModuleFlags::THIRTY_TWO_BIT));
+		assert_eq!((clean.bits(), clean.unknown_bits()), (1, 0));
+
+		let reserved = flags[1].unwrap();
+		assert!(!reserved.contains(ModuleFlags::THIRTY_TWO_BIT));
+		assert_eq!(reserved.unknown_bits(), 0x8000_0002);
+		assert_eq!(reserved, ModuleFlags::from_bits(0x8000_0002));
+
+		assert_eq!(flags[2], Some(ModuleFlags::default()));
+	}
+
+	#[test]
+	fn modules_containing_title() {
+		let rom = build_rom(&[
+			module(b"UtilityModule", &[]),
+			module(b"FileSwitch", &[]),
+			module(b"ADFS", &[]),
+			module(b"NetFS", &[]),
+			module(b"Broken", &[(0x10, 0x1000)]),
+		]);
+		assert_eq!(titles(rom.modules_containing_title(b"FS")), [&b"ADFS"[..], b"NetFS"]);
+		assert_eq!(titles(rom.modules_containing_title(b"Module")), [b"UtilityModule"]);
+		assert_eq!(rom.modules_containing_title(b"fs").count(), 0);
+		assert_eq!(rom.modules_containing_title(b"").count(), 4);
+	}
+
+	#[test]
+	fn empty() {
+		let rom = Rom::empty();
+		assert_eq!(rom.len(), 0);
+		assert_eq!(rom.kernel_start(), None);
+		assert_eq!(rom.module_chain_start(), None);
+		assert_eq!(rom.module_chain().count(), 0);
+		assert!(matches!(rom.identify(), Identification::Unknown));
+		assert_eq!(rom.version_string(), None);
+		assert_eq!(rom.footer(), None);
+		assert!(!rom.looks_valid());
+	}
+}
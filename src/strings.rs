@@ -0,0 +1,90 @@
+use std::{borrow::Borrow, iter::FusedIterator};
+
+use crate::{Rom, Slice32};
+
+const MONTHS: [&[u8; 3]; 12] = [
+	b"Jan", b"Feb", b"Mar", b"Apr", b"May", b"Jun", b"Jul", b"Aug", b"Sep", b"Oct", b"Nov", b"Dec",
+];
+
+impl<M: Borrow<[u8]>> Rom<M> {
+	/// Returns an iterator over each run of at least `min_len` printable characters in the
+	/// image, along with its offset, much like the Unix `strings` tool.
+	///
+	/// Printable characters are those of Latin-1 (the RISC OS character set) other than control
+	/// codes, plus tab. Runs needn't be NUL-terminated.
+	pub fn strings(&self, min_len: u32) -> Strings<'_> {
+		Strings { rem: self.as_slice32(), pos: 0, min_len: min_len.max(1) }
+	}
+
+	/// Returns the strings in the image that look like copyright notices: those containing
+	/// `(C)`, `(c)`, `©` or `Acorn`.
+	pub fn copyright_strings(&self) -> impl FusedIterator<Item = (u32, &Slice32)> + '_ {
+		const MARKERS: [&[u8]; 4] = [b"(C)", b"(c)", b"\xa9", b"Acorn"];
+		self.strings(4).filter(|(_, s)| {
+			MARKERS.iter().any(|m| s.as_ref().windows(m.len()).any(|w| w == *m))
+		})
+	}
+
+	/// Returns the strings in the image that contain a date in the form `dd Mmm yyyy`, such as
+	/// `29 Sep 1992`, as used in module help strings and the kernel version.
+	///
+	/// The day may be one or two digits, and the month must be an English abbreviation.
+	pub fn date_strings(&self) -> impl FusedIterator<Item = (u32, &Slice32)> + '_ {
+		self.strings(10).filter(|(_, s)| contains_date(s.as_ref()))
+	}
+}
+
+/// An iterator over the printable strings in a ROM image.
+///
+/// Returned by [`Rom::strings`].
+#[derive(Clone)]
+pub struct Strings<'a> {
+	rem: &'a Slice32,
+	/// Offset of `rem` within the image.
+	pos: u32,
+	min_len: u32,
+}
+
+impl<'a> Iterator for Strings<'a> {
+	type Item = (u32, &'a Slice32);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			let start = self.rem.as_ref().iter().position(|&b| is_printable(b))? as u32;
+			let len = self.rem.as_ref()[start as usize ..].iter()
+				.position(|&b| !is_printable(b))
+				.map_or(self.rem.len() - start, |n| n as u32);
+
+			let run = self.rem.subslice(start .. start + len).unwrap();
+			let offset = self.pos + start;
+			self.rem = self.rem.subslice_from(start + len).unwrap();
+			self.pos += start + len;
+
+			if len >= self.min_len {
+				return Some((offset, run));
+			}
+		}
+	}
+}
+
+impl<'a> FusedIterator for Strings<'a> { }
+
+fn is_printable(b: u8) -> bool {
+	matches!(b, b'\t' | 0x20 ..= 0x7e | 0xa0 ..= 0xff)
+}
+
+/// Returns `true` if `s` contains a date of the form `dd Mmm yyyy`.
+fn contains_date(s: &[u8]) -> bool {
+	(0 .. s.len()).any(|at| {
+		let month = &s[at..];
+		if !MONTHS.iter().any(|m| month.starts_with(*m)) {
+			return false;
+		}
+
+		// a space either side of the month, with 4 digits after and 1 or 2 digits before
+		let year = month.get(3 .. 8);
+		let year_ok = year.is_some_and(|y| y[0] == b' ' && y[1..].iter().all(u8::is_ascii_digit));
+		let day_ok = at >= 2 && s[at - 1] == b' ' && s[at - 2].is_ascii_digit();
+		year_ok && day_ok
+	})
+}
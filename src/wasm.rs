@@ -0,0 +1,57 @@
+//! JavaScript bindings, for inspecting ROM images in the browser.
+//!
+//! The ROM bytes are copied out of the JS `Uint8Array` into the module's linear memory, so a
+//! full-size (12 MiB) image needs that much heap; `wasm32-unknown-unknown` grows its memory on
+//! demand, so no special configuration is required.
+
+use wasm_bindgen::prelude::*;
+
+use crate::{Identification, Rom};
+
+/// A ROM image loaded from JavaScript.
+#[wasm_bindgen]
+pub struct WasmRom(Rom);
+
+/// Summary of one module in the ROM chain.
+#[wasm_bindgen(getter_with_clone)]
+pub struct ModuleEntry {
+	/// The module title, decoded as Latin-1, or a `<unnamed@0x…>` placeholder if it's empty or
+	/// can't be read.
+	pub name: String,
+	/// The offset of the module within the ROM image.
+	pub offset: u32,
+	/// The module length in bytes.
+	pub length: u32,
+}
+
+/// Loads a ROM image from a `Uint8Array`.
+#[wasm_bindgen]
+pub fn load(bytes: &[u8]) -> Result<WasmRom, JsError> {
+	Rom::from_mem(Box::<[u8]>::from(bytes))
+		.map(WasmRom)
+		.map_err(|e| JsError::new(&e.to_string()))
+}
+
+#[wasm_bindgen]
+impl WasmRom {
+	/// Returns every module in the ROM chain, as for [`Rom::module_names_lossy`].
+	pub fn module_list(&self) -> Vec<ModuleEntry> {
+		self.0.module_chain().map(|m| ModuleEntry {
+			name: m.lossy_title(),
+			offset: m.offset(),
+			length: m.data().len(),
+		}).collect()
+	}
+
+	/// Returns the colloquial name of the ROM's RISC OS version, if it is a known image.
+	///
+	/// A copy that carries a known version string but has been modified is reported with a
+	/// `(patched)` suffix.
+	pub fn detect_version(&self) -> Option<String> {
+		match self.0.identify() {
+			Identification::Identified(v) => Some(v.name_high_level.to_owned()),
+			Identification::PatchedVariant(v) => Some(format!("{} (patched)", v.name_high_level)),
+			Identification::Unknown => None,
+		}
+	}
+}